@@ -0,0 +1,48 @@
+//! Times finding the first and last digit of generated lines with the
+//! automata of [`Matcher`], against finding every match and sorting them.
+//!
+//! [`Matcher`]: advent_of_code_2023::day01::Matcher
+
+use advent_of_code_2023::bench::time;
+use advent_of_code_2023::day01::{find_matches, find_matches_sorted, REPLACEMENTS};
+
+/// Lines of lowercase letters, with digits and digit words here and there,
+/// `length` bytes long or a few more. The same lines every time.
+fn generate(lines: usize, length: usize) -> String {
+    // xorshift, seeded with anything but 0
+    let mut state = 0x2023_1201_u64;
+    let mut random = move |below: u64| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state % below
+    };
+    let mut input = String::new();
+    for _ in 0..lines {
+        let start = input.len();
+        while input.len() - start < length {
+            match random(40) {
+                0 => input.push(char::from(b'0' + random(10) as u8)),
+                1 => input += REPLACEMENTS[random(9) as usize].0,
+                _ => input.push(char::from(b'a' + random(26) as u8)),
+            }
+        }
+        input.push('\n');
+    }
+    input
+}
+
+fn main() {
+    for (lines, length) in [(200_000, 30), (5_000, 2000)] {
+        let input = generate(lines, length);
+        let (sorted, expected) = time(5, || {
+            input.lines().map(find_matches_sorted).collect::<Vec<_>>()
+        });
+        let (matcher, found) = time(5, || input.lines().map(find_matches).collect::<Vec<_>>());
+        assert_eq!(found, expected);
+        println!(
+            "{lines} lines of {length} bytes: sorted {:.2?}, matcher {:.2?} (medians)",
+            sorted.median, matcher.median
+        );
+    }
+}
@@ -0,0 +1,40 @@
+#![no_main]
+
+use advent_of_code_2023::day01::{
+    explain_line, find_digits, find_digits_with, find_matches, find_matches_sorted,
+    find_matches_with, Day01, Dictionary, Digits, Matches, NoDigits, Options,
+};
+use advent_of_code_2023::policy::Policy;
+use advent_of_code_2023_fuzz::exercise;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    for line in input.lines() {
+        if let Some(digits) = find_digits(line) {
+            digits.combine();
+        }
+        let matches = find_matches(line);
+        assert_eq!(matches, find_matches_sorted(line));
+        if let Some(digits) = matches {
+            digits.combine();
+        }
+        // digits of every script, against finding every match
+        let unicode = find_matches_with(line, Digits::Unicode);
+        let sorted = Matches::find_with(line, Digits::Unicode).calibration_digits();
+        assert_eq!(unicode, sorted);
+        if let (Some(digits), Some(words)) = (find_digits_with(line, Digits::Unicode), unicode) {
+            digits.combine();
+            words.combine();
+        }
+        explain_line(0, line, Digits::Unicode, true);
+    }
+    // the input as a dictionary, solving itself
+    if let Ok(dictionary) = input.parse::<Dictionary>() {
+        let options = Options {
+            digits: Digits::Unicode,
+            no_digits: NoDigits::Skip,
+        };
+        let _ = dictionary.solve(input, Policy::Lenient, options);
+    }
+    exercise(&Day01, input);
+});
@@ -0,0 +1,14 @@
+#![no_main]
+
+use advent_of_code_2023::day02::{Day02, FewestCubes, GameRecord};
+use advent_of_code_2023_fuzz::exercise;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(game) = GameRecord::try_from(input) {
+        game.invalid();
+        FewestCubes::from(&game).power();
+        assert_eq!(GameRecord::try_from(game.to_string().as_str()), Ok(game));
+    }
+    exercise(&Day02, input);
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use advent_of_code_2023::day03::{Day03, Grid};
+use advent_of_code_2023_fuzz::exercise;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(grid) = Grid::try_from(input) {
+        grid.part_numbers();
+        assert_eq!(Grid::try_from(grid.to_string().as_str()), Ok(grid));
+    }
+    exercise(&Day03, input);
+});
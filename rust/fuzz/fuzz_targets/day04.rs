@@ -0,0 +1,16 @@
+#![no_main]
+
+use advent_of_code_2023::day04::{Card, Cards, Day04};
+use advent_of_code_2023_fuzz::exercise;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(card) = Card::try_from(input) {
+        card.points();
+        assert_eq!(Card::try_from(card.to_string().as_str()), Ok(card));
+    }
+    if let Ok(cards) = Cards::try_from(input) {
+        assert_eq!(Cards::try_from(cards.to_string().as_str()), Ok(cards));
+    }
+    exercise(&Day04, input);
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use advent_of_code_2023::day05::{Day05, Input};
+use advent_of_code_2023_fuzz::exercise;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(parsed) = Input::try_from(input) {
+        assert_eq!(Input::try_from(parsed.to_string().as_str()), Ok(parsed));
+    }
+    exercise(&Day05, input);
+});
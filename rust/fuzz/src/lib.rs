@@ -0,0 +1,82 @@
+//! What every fuzz target checks: a day's input may be rejected with an
+//! error, but parsing, validating and solving it must never panic.
+
+use advent_of_code_2023::error::Error;
+use advent_of_code_2023::policy::Policy;
+use advent_of_code_2023::solution::{Day, Part};
+use advent_of_code_2023::stream::StreamError;
+
+/// Runs a day's whole pipeline on `input` under both policies, rendering
+/// every error the way the runner does. Parsed inputs must also solve the
+/// same after a round trip through JSON, and every parser backend of the day
+/// must accept the same inputs, parsed the same. Solving from the bytes of
+/// the input must agree with solving the parsed input, and so must streaming
+/// it.
+pub fn exercise(day: &dyn Day, input: &str) {
+    for policy in [Policy::Lenient, Policy::Strict] {
+        for error in day.validate(input, policy).errors {
+            let _ = format!("{error:#}");
+        }
+        let parsed = match day.parse_input(input, policy) {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                let _ = format!("{error:#}");
+                // every parser must reject the input too
+                for &backend in day.backends() {
+                    if let Ok(parsed) = day.parse_with(input, policy, backend) {
+                        panic!("{backend} parsed {}", day.to_json(&parsed));
+                    }
+                }
+                for part in [Part::One, Part::Two] {
+                    match day.solve_bytes(input, policy, part) {
+                        Ok(answer) => panic!("solved from bytes: {answer}"),
+                        Err(Error::Unimplemented { .. }) => {}
+                        Err(bytes) => assert_eq!(bytes.line(), error.line(), "{bytes:#}"),
+                    }
+                    match day.stream(&mut input.as_bytes(), policy, part) {
+                        Ok(answer) => panic!("streamed: {answer}"),
+                        Err(StreamError::Solve(Error::Unimplemented { .. })) => {}
+                        // streams stop at the first bad line, which may be
+                        // before the first layout error
+                        Err(StreamError::Solve(streamed)) => {
+                            assert!(streamed.line() <= error.line(), "{streamed:#}")
+                        }
+                        Err(StreamError::Read(e)) => panic!("failed to read: {e}"),
+                    }
+                }
+                continue;
+            }
+        };
+        let json = day.to_json(&parsed);
+        for &backend in day.backends() {
+            match day.parse_with(input, policy, backend) {
+                Ok(other) => assert_eq!(day.to_json(&other), json, "{backend}"),
+                Err(error) => panic!("{backend} rejected the input: {error:#}"),
+            }
+        }
+        let from_json = day
+            .parse_json(&json)
+            .expect("a parsed input reads back from JSON");
+        for part in [Part::One, Part::Two] {
+            let answer = day.solve(&parsed, part);
+            assert_eq!(day.solve(&from_json, part), answer);
+            match day.solve_bytes(input, policy, part) {
+                Err(Error::Unimplemented { .. }) => {}
+                bytes => assert_eq!(bytes, answer, "from bytes"),
+            }
+            // solve errors may point at other lines, as solving a parsed
+            // input doesn't locate them in the original
+            match day.stream(&mut input.as_bytes(), policy, part) {
+                Err(StreamError::Solve(Error::Unimplemented { .. })) => {}
+                Ok(streamed) => assert_eq!(Ok(&streamed), answer.as_ref(), "streamed"),
+                Err(StreamError::Solve(streamed)) => {
+                    assert!(answer.is_err(), "streamed: {streamed:#}")
+                }
+                Err(StreamError::Read(e)) => panic!("failed to read: {e}"),
+            }
+            if let Err(error) = answer {
+                let _ = format!("{error:#}");
+            }
+        }
+    }
+}
@@ -0,0 +1,137 @@
+//! Known answers for puzzle inputs, so a refactor can't silently change an
+//! answer that was already accepted.
+//!
+//! Answers are stored one per line as `day part input-hash answer`:
+//!
+//! ```text
+//! # day part input answer
+//! 01 1 5b123a982cd5f9a2 54916
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::input;
+use crate::solution::Part;
+
+/// Name of the answers file inside the inputs directory.
+pub const FILE_NAME: &str = "answers.txt";
+
+/// FNV-1a hash of a puzzle input, identifying which input an answer belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InputHash(u64);
+
+impl InputHash {
+    pub fn of(input: &str) -> Self {
+        let mut hash: u64 = 0xcbf29ce484222325;
+        for byte in input.bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+        InputHash(hash)
+    }
+}
+
+impl fmt::Display for InputHash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+impl FromStr for InputHash {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        u64::from_str_radix(s, 16)
+            .map(InputHash)
+            .map_err(|e| format!("invalid input hash {s:?}: {e}"))
+    }
+}
+
+/// Known answers, keyed by day, part and input.
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers(BTreeMap<(u8, Part, InputHash), String>);
+
+impl Answers {
+    /// The known answers in a file (see [`input::load`]).
+    pub fn load(path: &Path) -> io::Result<Self> {
+        input::load(path)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, day: u8, part: Part, input: InputHash) -> Option<&str> {
+        self.0.get(&(day, part, input)).map(String::as_str)
+    }
+
+    /// Returns the previously recorded answer, if any.
+    pub fn record(
+        &mut self,
+        day: u8,
+        part: Part,
+        input: InputHash,
+        answer: String,
+    ) -> Option<String> {
+        self.0.insert((day, part, input), answer)
+    }
+}
+
+impl FromStr for Answers {
+    type Err = String;
+
+    fn from_str(contents: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::default();
+        for (index, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let &[day, part, input, answer] = fields.as_slice() else {
+                return Err(format!("line {}: expected 4 fields", index + 1));
+            };
+            let day = day
+                .parse::<u8>()
+                .map_err(|e| format!("line {}: invalid day: {e}", index + 1))?;
+            let part = part
+                .parse::<Part>()
+                .map_err(|e| format!("line {}: {e}", index + 1))?;
+            let input = input
+                .parse::<InputHash>()
+                .map_err(|e| format!("line {}: {e}", index + 1))?;
+            answers.record(day, part, input, answer.into());
+        }
+        Ok(answers)
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "# day part input answer")?;
+        for ((day, part, input), answer) in &self.0 {
+            writeln!(f, "{day:02} {part} {input} {answer}")?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn round_trip() {
+    let example = include_str!("../../input/day01/example01.txt");
+    let mut answers = Answers::default();
+    answers.record(1, Part::One, InputHash::of(example), "142".into());
+    answers.record(5, Part::Two, InputHash::of(""), "46".into());
+    let parsed: Answers = answers.to_string().parse().unwrap();
+    assert_eq!(parsed, answers);
+    assert_eq!(
+        parsed.get(1, Part::One, InputHash::of(example)),
+        Some("142")
+    );
+    assert_eq!(parsed.get(1, Part::Two, InputHash::of(example)), None);
+}
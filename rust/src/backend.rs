@@ -0,0 +1,51 @@
+//! Choosing between the parsers of days that have more than one.
+
+use std::fmt;
+
+use clap::ValueEnum;
+
+#[cfg(test)]
+use crate::solution::Solution;
+
+/// The library a parser is written with.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, ValueEnum)]
+pub enum Backend {
+    /// A pest grammar (`src/dayNN.pest`).
+    #[default]
+    Pest,
+    /// nom parser combinators (`src/dayNN/nom.rs`).
+    Nom,
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Backend::Pest => write!(f, "pest"),
+            Backend::Nom => write!(f, "nom"),
+        }
+    }
+}
+
+/// Checks that every backend of a day parses `input` to the same thing, or
+/// rejects it on the same line. Only lines are compared, as pest doesn't
+/// track literals, so its errors may point further back.
+#[cfg(test)]
+pub fn assert_agree<S>(input: &str)
+where
+    S: Solution,
+    S::Input: PartialEq + fmt::Debug,
+{
+    let [first, others @ ..] = S::BACKENDS else {
+        return;
+    };
+    let expected = S::parse_with(*first, input);
+    for &backend in others {
+        match (&expected, S::parse_with(backend, input)) {
+            (Ok(expected), Ok(parsed)) => assert_eq!(expected, &parsed, "{backend}: {input}"),
+            (Err(expected), Err(error)) => {
+                assert_eq!(expected.line(), error.line(), "{backend}: {input}")
+            }
+            (expected, parsed) => panic!("{input}: {first} {expected:?} vs {backend} {parsed:?}"),
+        }
+    }
+}
@@ -0,0 +1,248 @@
+//! Timing parsing and each part of a day separately, over many iterations.
+
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use clap::ValueEnum;
+
+use crate::backend::Backend;
+use crate::error::Error;
+use crate::policy::Policy;
+use crate::solution::{Day, Part};
+
+/// A separately timed stage of solving a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    /// Parsing, with the backend of days that have several.
+    Parse(Option<Backend>),
+    Solve(Part),
+    /// Parsing and solving a part from the bytes of the input.
+    #[cfg(feature = "fast")]
+    Bytes(Part),
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Phase::Parse(None) => write!(f, "parse"),
+            Phase::Parse(Some(backend)) => write!(f, "parse-{backend}"),
+            Phase::Solve(part) => write!(f, "part{part}"),
+            #[cfg(feature = "fast")]
+            Phase::Bytes(part) => write!(f, "bytes-part{part}"),
+        }
+    }
+}
+
+/// Summary of repeated timings of one phase.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub max: Duration,
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Panics if there are no samples.
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarize");
+        samples.sort();
+        let iterations = samples.len();
+        let nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / iterations as f64;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / iterations as f64;
+        let median = if iterations.is_multiple_of(2) {
+            (samples[iterations / 2 - 1] + samples[iterations / 2]) / 2
+        } else {
+            samples[iterations / 2]
+        };
+        Stats {
+            iterations,
+            min: samples[0],
+            max: samples[iterations - 1],
+            mean: Duration::from_nanos(mean as u64),
+            median,
+            stddev: Duration::from_nanos(variance.sqrt() as u64),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Measurement {
+    pub day: u8,
+    pub phase: Phase,
+    pub stats: Stats,
+}
+
+/// Times `f` over some iterations, returning the last result.
+pub fn time<T>(iterations: usize, mut f: impl FnMut() -> T) -> (Stats, T) {
+    let mut samples = Vec::with_capacity(iterations);
+    let mut result = None;
+    for _ in 0..iterations.max(1) {
+        let start = Instant::now();
+        let output = black_box(f());
+        samples.push(start.elapsed());
+        result = Some(output);
+    }
+    (Stats::from_samples(&mut samples), result.unwrap())
+}
+
+/// Times parsing (including the policy's normalization) with every parser
+/// backend of a day, and every implemented part. With the `fast` feature,
+/// also times solving each part straight from the bytes of the input.
+pub fn bench(
+    solver: &dyn Day,
+    input: &str,
+    policy: Policy,
+    iterations: usize,
+) -> Result<Vec<Measurement>, Error> {
+    let day = solver.number();
+    // days with a single parser have no backends to name
+    let backends: Vec<Option<Backend>> = match solver.backends() {
+        [] => vec![None],
+        backends => backends.iter().copied().map(Some).collect(),
+    };
+    let mut measurements = Vec::new();
+    for backend in backends {
+        let (stats, parsed) = time(iterations, || {
+            solver.parse_with(input, policy, backend.unwrap_or_default())
+        });
+        parsed?;
+        measurements.push(Measurement {
+            day,
+            phase: Phase::Parse(backend),
+            stats,
+        });
+    }
+    let parsed = solver.parse_input(input, policy)?;
+    for part in Part::BOTH {
+        let (stats, answer) = time(iterations, || solver.solve(&parsed, part));
+        match answer {
+            Ok(_) => measurements.push(Measurement {
+                day,
+                phase: Phase::Solve(part),
+                stats,
+            }),
+            Err(Error::Unimplemented { .. }) => {}
+            Err(e) => return Err(e),
+        }
+    }
+    #[cfg(feature = "fast")]
+    for part in Part::BOTH {
+        let (stats, answer) = time(iterations, || solver.solve_bytes(input, policy, part));
+        match answer {
+            Ok(_) => measurements.push(Measurement {
+                day,
+                phase: Phase::Bytes(part),
+                stats,
+            }),
+            Err(Error::Unimplemented { .. }) => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(measurements)
+}
+
+/// How to print benchmark results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+pub fn render(measurements: &[Measurement], format: Format) -> String {
+    match format {
+        Format::Text => render_text(measurements),
+        Format::Json => render_json(measurements),
+        Format::Csv => render_csv(measurements),
+    }
+}
+
+fn render_text(measurements: &[Measurement]) -> String {
+    let mut out = format!(
+        "{:<4} {:<11} {:>6} {:>12} {:>12} {:>12} {:>12} {:>12}\n",
+        "day", "phase", "iters", "min", "median", "mean", "max", "stddev"
+    );
+    for Measurement { day, phase, stats } in measurements {
+        out += &format!(
+            "{:<4} {:<11} {:>6} {:>12} {:>12} {:>12} {:>12} {:>12}\n",
+            format!("{day:02}"),
+            phase.to_string(),
+            stats.iterations,
+            format!("{:.2?}", stats.min),
+            format!("{:.2?}", stats.median),
+            format!("{:.2?}", stats.mean),
+            format!("{:.2?}", stats.max),
+            format!("{:.2?}", stats.stddev),
+        );
+    }
+    out
+}
+
+/// Durations are in nanoseconds.
+fn render_json(measurements: &[Measurement]) -> String {
+    let rows: Vec<String> = measurements
+        .iter()
+        .map(|Measurement { day, phase, stats }| {
+            format!(
+                r#"  {{"day": {day}, "phase": "{phase}", "iterations": {}, "min_ns": {}, "median_ns": {}, "mean_ns": {}, "max_ns": {}, "stddev_ns": {}}}"#,
+                stats.iterations,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.mean.as_nanos(),
+                stats.max.as_nanos(),
+                stats.stddev.as_nanos(),
+            )
+        })
+        .collect();
+    format!("[\n{}\n]\n", rows.join(",\n"))
+}
+
+/// Durations are in nanoseconds.
+fn render_csv(measurements: &[Measurement]) -> String {
+    let mut out = String::from("day,phase,iterations,min_ns,median_ns,mean_ns,max_ns,stddev_ns\n");
+    for Measurement { day, phase, stats } in measurements {
+        out += &format!(
+            "{day},{phase},{},{},{},{},{},{}\n",
+            stats.iterations,
+            stats.min.as_nanos(),
+            stats.median.as_nanos(),
+            stats.mean.as_nanos(),
+            stats.max.as_nanos(),
+            stats.stddev.as_nanos(),
+        );
+    }
+    out
+}
+
+#[test]
+fn stats() {
+    let mut samples: Vec<Duration> = [4, 1, 3, 2].map(Duration::from_millis).to_vec();
+    let stats = Stats::from_samples(&mut samples);
+    assert_eq!(stats.iterations, 4);
+    assert_eq!(stats.min, Duration::from_millis(1));
+    assert_eq!(stats.max, Duration::from_millis(4));
+    assert_eq!(stats.median, Duration::from_micros(2500));
+    assert_eq!(stats.mean, Duration::from_micros(2500));
+    assert_eq!(stats.stddev, Duration::from_nanos(1118033));
+}
+
+#[test]
+fn csv() {
+    let example = include_str!("../../input/day04/example01.txt");
+    let measurements = bench(&crate::day04::Day04, example, Policy::Strict, 3).unwrap();
+    let csv = render(&measurements, Format::Csv);
+    let rows: Vec<&str> = csv.lines().collect();
+    // and `bytes-part1` and `bytes-part2` with the `fast` feature
+    assert_eq!(rows.len(), if cfg!(feature = "fast") { 7 } else { 5 });
+    assert!(rows[1].starts_with("4,parse-pest,3,"));
+    assert!(rows[2].starts_with("4,parse-nom,3,"));
+    assert!(rows[3].starts_with("4,part1,3,"));
+    assert!(rows[4].starts_with("4,part2,3,"));
+    let measurements = bench(&crate::day01::Day01, "1abc2\n", Policy::Strict, 1).unwrap();
+    assert_eq!(measurements[0].phase, Phase::Parse(None));
+}
@@ -0,0 +1,43 @@
+//! Helpers for the parsers over the bytes of an input (`src/dayNN/bytes.rs`).
+
+/// The lines of `input` like [`str::lines`]: split at `\n` or `\r\n`,
+/// without an empty line after a final line break.
+pub fn lines(input: &[u8]) -> impl Iterator<Item = &[u8]> {
+    input
+        .split_inclusive(|&byte| byte == b'\n')
+        .map(|line| match line.strip_suffix(b"\n") {
+            Some(line) => line.strip_suffix(b"\r").unwrap_or(line),
+            None => line,
+        })
+}
+
+/// The number of ASCII digits at the start of `bytes`.
+pub fn digits(bytes: &[u8]) -> usize {
+    bytes
+        .iter()
+        .take_while(|byte| byte.is_ascii_digit())
+        .count()
+}
+
+/// The value of ASCII digits, `None` if it overflows a `u64`.
+pub fn number(digits: &[u8]) -> Option<u64> {
+    digits.iter().try_fold(0u64, |number, digit| {
+        number.checked_mul(10)?.checked_add(u64::from(digit - b'0'))
+    })
+}
+
+/// Whether a byte starts a character, rather than continuing one.
+pub fn starts_char(byte: u8) -> bool {
+    byte & 0b1100_0000 != 0b1000_0000
+}
+
+#[test]
+fn like_str() {
+    for input in ["", "a", "a\n", "a\r\nb", "a\r", "a\n\nb\r\r\n", "\n"] {
+        let bytes: Vec<&[u8]> = lines(input.as_bytes()).collect();
+        let str: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+        assert_eq!(bytes, str, "{input:?}");
+    }
+    assert_eq!(number(b"0018446744073709551615"), Some(u64::MAX));
+    assert_eq!(number(b"18446744073709551616"), None);
+}
@@ -0,0 +1,481 @@
+use std::io::BufRead;
+
+use clap::ValueEnum;
+
+use crate::error::{Error, Span};
+use crate::policy::{Layout, Policy};
+use crate::solution::{Part, Solution};
+use crate::stream::{self, StreamError};
+use crate::validate::Report;
+
+mod automaton;
+mod dictionary;
+mod explain;
+mod unicode;
+
+pub use automaton::Matcher;
+pub use dictionary::Dictionary;
+pub use explain::{explain, explain_line};
+pub use unicode::UNICODE_VERSION;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<String>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    /// Every line needs a digit to be solvable, as part 1 doesn't read
+    /// digit words.
+    fn validate(input: &str) -> Report {
+        Report::lines("lines", input, |line| {
+            calibration_value(0, line, Part::One, Digits::Ascii).map_err(|e| {
+                match find_matches(line) {
+                    Some(_) => Error::invalid(
+                        Self::DAY,
+                        Some(Span::line(0, line)),
+                        "only digit words, which part 1 doesn't read",
+                    ),
+                    None => e,
+                }
+            })
+        })
+    }
+
+    fn part1(lines: &Self::Input) -> Result<u64, Error> {
+        calibrate(lines, Part::One, Options::default()).map(|calibration| calibration.sum)
+    }
+
+    fn part2(lines: &Self::Input) -> Result<u64, Error> {
+        calibrate(lines, Part::Two, Options::default()).map(|calibration| calibration.sum)
+    }
+
+    fn stream(reader: impl BufRead, policy: Policy, part: Part) -> Result<u64, StreamError> {
+        let mut sum = 0;
+        // a line without digits only fails once every line has been read,
+        // like when solving a parsed input
+        let mut failed = None;
+        stream::lines(reader, Self::DAY, policy, Self::tidy, |index, line| {
+            match calibration_value(index, line, part, Digits::Ascii) {
+                Ok(value) => sum += value,
+                Err(e) => {
+                    failed.get_or_insert(e);
+                }
+            }
+            Ok(())
+        })?;
+        match failed {
+            Some(e) => Err(e.into()),
+            None => Ok(sum),
+        }
+    }
+}
+
+/// Which characters are digits.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Digits {
+    /// `0` to `9`, as in the puzzle.
+    #[default]
+    Ascii,
+    /// The decimal digits of every script as of Unicode 17.0, like `٣`, `３`
+    /// or `३`.
+    Unicode,
+}
+
+impl Digits {
+    pub fn is_digit(self, c: char) -> bool {
+        match self {
+            Digits::Ascii => c.is_ascii_digit(),
+            Digits::Unicode => unicode::digit(c).is_some(),
+        }
+    }
+
+    /// The values of the first and last matches of a line, from where the
+    /// first and last matches of `0` to `9` and words start (`found`), and
+    /// the digits of other scripts if they count.
+    fn first_and_last<T>(
+        self,
+        line: &str,
+        found: Option<((usize, T), (usize, T))>,
+        value: impl Fn(char) -> T,
+    ) -> Option<(T, T)> {
+        let (mut first, mut last) = found.unzip();
+        if self == Digits::Unicode {
+            // only the text before the first match and after the last can
+            // hold digits of other scripts that come first or last
+            let before = first.as_ref().map_or(line.len(), |(start, _)| *start);
+            let mut digits = line[..before].char_indices();
+            if let Some((start, c)) = digits.find(|&(_, c)| self.is_digit(c)) {
+                first = Some((start, value(c)));
+            }
+            let after = last.as_ref().map_or(0, |(start, _)| {
+                start + line[*start..].chars().next().map_or(0, char::len_utf8)
+            });
+            let mut digits = line[after..].char_indices();
+            if let Some((start, c)) = digits.rfind(|&(_, c)| self.is_digit(c)) {
+                last = Some((after + start, value(c)));
+            }
+        }
+        Some((first?.1, last?.1))
+    }
+}
+
+/// What to do with lines without digits (or digit words, in part 2).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum NoDigits {
+    /// Fail on the first one, with its line number.
+    #[default]
+    Error,
+    /// Leave them out of the calibration.
+    Skip,
+    /// Count them as a calibration value of 0.
+    Zero,
+}
+
+/// The sum of the calibration values of a document, and how many lines
+/// without digits were skipped or counted as zero.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Calibration {
+    pub sum: u64,
+    pub affected: usize,
+}
+
+/// How lines are calibrated, beyond the rules of the puzzle.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    pub digits: Digits,
+    pub no_digits: NoDigits,
+}
+
+/// Calibrates parsed lines for a part.
+pub fn calibrate(lines: &[String], part: Part, options: Options) -> Result<Calibration, Error> {
+    calibrate_with(
+        lines.iter().map(String::as_str),
+        options.no_digits,
+        |index, line| calibration_value(index, line, part, options.digits),
+    )
+}
+
+/// Calibrates every line with `value`, which only fails for lines without
+/// digits. Sums that overflow fail whatever `no_digits` is.
+fn calibrate_with<'a>(
+    lines: impl IntoIterator<Item = &'a str>,
+    no_digits: NoDigits,
+    value: impl Fn(usize, &str) -> Result<u64, Error>,
+) -> Result<Calibration, Error> {
+    let mut calibration = Calibration::default();
+    for (index, line) in lines.into_iter().enumerate() {
+        match value(index, line) {
+            Ok(value) => {
+                calibration.sum = calibration
+                    .sum
+                    .checked_add(value)
+                    .ok_or(Error::overflow(Day01::DAY))?;
+            }
+            Err(e) if no_digits == NoDigits::Error => return Err(e),
+            Err(_) => calibration.affected += 1,
+        }
+    }
+    Ok(calibration)
+}
+
+/// Calibrates a part of an input prepared under a policy, like
+/// [`Day01`]'s parts with other options.
+pub fn solve(
+    input: &str,
+    policy: Policy,
+    part: Part,
+    options: Options,
+) -> Result<Calibration, Error> {
+    let prepared = policy.prepare(Day01::DAY, Layout::Lines, input, Day01::tidy)?;
+    let lines: Vec<String> = prepared.text.lines().map(String::from).collect();
+    calibrate(&lines, part, options).map_err(|e| prepared.locate(e))
+}
+
+/// The calibration value of the (0-based) `index`th line for a part.
+fn calibration_value(index: usize, line: &str, part: Part, digits: Digits) -> Result<u64, Error> {
+    let (digits, missing) = match part {
+        Part::One => (find_digits_with(line, digits), "no digits"),
+        Part::Two => (find_matches_with(line, digits), "no digits or digit words"),
+    };
+    digits
+        .map(CalibrationDigits::combine)
+        .ok_or_else(|| Error::invalid(Day01::DAY, Some(Span::line(index, line)), missing))
+}
+
+/// The first and last digit of a line, which only this module makes, out
+/// of decimal digits.
+#[derive(Debug, PartialEq)]
+pub struct CalibrationDigits(char, char);
+
+/// `None` if the line has no digits.
+pub fn find_digits(line: &str) -> Option<CalibrationDigits> {
+    find_digits_with(line, Digits::Ascii)
+}
+
+/// Like [`find_digits`], for a choice of digits.
+pub fn find_digits_with(line: &str, digits: Digits) -> Option<CalibrationDigits> {
+    let mut digits = line.chars().filter(|&c| digits.is_digit(c));
+    let first = digits.next()?;
+    Some(CalibrationDigits(
+        first,
+        digits.next_back().unwrap_or(first),
+    ))
+}
+
+impl CalibrationDigits {
+    /// The two digits as a number, whatever their script.
+    pub fn combine(self) -> u64 {
+        let CalibrationDigits(first, last) = self;
+        let value = |digit| u64::from(unicode::digit(digit).expect("a decimal digit"));
+        value(first) * 10 + value(last)
+    }
+}
+
+pub const REPLACEMENTS: [(&str, char); 9] = [
+    ("one", '1'),
+    ("two", '2'),
+    ("three", '3'),
+    ("four", '4'),
+    ("five", '5'),
+    ("six", '6'),
+    ("seven", '7'),
+    ("eight", '8'),
+    ("nine", '9'),
+];
+
+pub struct Match {
+    pub index: usize,
+    /// The length in bytes of the digit or digit word.
+    pub length: usize,
+    pub value: char,
+}
+
+pub struct Matches(Vec<Match>);
+
+/// `None` if the line has neither digits nor digit words.
+pub fn find_matches<S: AsRef<str>>(line: S) -> Option<CalibrationDigits> {
+    find_matches_with(line, Digits::Ascii)
+}
+
+/// Like [`find_matches`], for a choice of digits.
+pub fn find_matches_with<S: AsRef<str>>(line: S, digits: Digits) -> Option<CalibrationDigits> {
+    let line = line.as_ref();
+    // the digits, then the words
+    let value = |pattern: usize| match pattern.checked_sub(10) {
+        Some(word) => REPLACEMENTS[word].1,
+        None => char::from(b'0' + pattern as u8),
+    };
+    let found = Matcher::digits()
+        .find_at(line)
+        .map(|((first, f), (last, l))| ((first, value(f)), (last, value(l))));
+    let (first, last) = digits.first_and_last(line, found, |digit| digit)?;
+    Some(CalibrationDigits(first, last))
+}
+
+/// Like [`find_matches`], by finding every match of every pattern and
+/// sorting them, which [`Matcher`] is checked and benchmarked against.
+pub fn find_matches_sorted<S: AsRef<str>>(line: S) -> Option<CalibrationDigits> {
+    Matches::find(line.as_ref()).calibration_digits()
+}
+
+impl Matches {
+    /// Every digit and digit word of a line, overlapping ones included.
+    pub fn find(line: &str) -> Self {
+        Matches::find_with(line, Digits::Ascii)
+    }
+
+    /// Like [`Matches::find`], for a choice of digits.
+    pub fn find_with(line: &str, digits: Digits) -> Self {
+        let mut matches = vec![];
+        // find matches for every word
+        for (pattern, value) in REPLACEMENTS {
+            let mut found: Vec<Match> = line
+                .match_indices(pattern)
+                .map(|(index, _)| Match {
+                    index,
+                    length: pattern.len(),
+                    value,
+                })
+                .collect();
+            matches.append(&mut found);
+        }
+        // find matches for all digits
+        let mut digit_matches: Vec<Match> = line
+            .match_indices(|c: char| digits.is_digit(c))
+            .map(|(index, value)| Match {
+                index,
+                length: value.len(),
+                value: value.chars().next().unwrap(),
+            })
+            .collect();
+        matches.append(&mut digit_matches);
+        Matches(matches)
+    }
+
+    pub fn calibration_digits(&mut self) -> Option<CalibrationDigits> {
+        self.0.sort_by_key(|m| m.index);
+        Some(CalibrationDigits(
+            self.0.first()?.value,
+            self.0.last()?.value,
+        ))
+    }
+}
+
+#[test]
+fn example01() {
+    let example = include_str!("../../input/day01/example01.txt");
+    let result: u64 = example
+        .lines()
+        .map(|line| find_digits(line).unwrap().combine())
+        .sum();
+    assert_eq!(result, 142);
+}
+
+#[test]
+fn example02() {
+    let example = include_str!("../../input/day01/example02.txt");
+    let lines = Day01::parse(example).unwrap();
+    assert_eq!(Day01::part2(&lines), Ok(281));
+}
+
+#[test]
+fn no_digits() {
+    let lines = Day01::parse("1abc2\npqrstu\n").unwrap();
+    assert_eq!(
+        Day01::part1(&lines).unwrap_err().to_string(),
+        "day 01, line 2, column 1, at \"pqrstu\": no digits"
+    );
+    assert_eq!(Day01::part2(&lines).unwrap_err().day(), 1);
+}
+
+#[test]
+fn lines_without_digits() {
+    let input = "1abc2\n\npqrstu\nseven\n";
+    let solved = |part, no_digits| {
+        let options = Options {
+            no_digits,
+            ..Options::default()
+        };
+        solve(input, Policy::Lenient, part, options)
+    };
+    let error = solved(Part::One, NoDigits::Error).unwrap_err();
+    assert_eq!(error.line(), Some(3));
+    let skipped = Calibration {
+        sum: 12,
+        affected: 2,
+    };
+    assert_eq!(solved(Part::One, NoDigits::Skip), Ok(skipped));
+    assert_eq!(solved(Part::One, NoDigits::Zero), Ok(skipped));
+    let zero = Calibration {
+        sum: 89,
+        affected: 1,
+    };
+    assert_eq!(solved(Part::Two, NoDigits::Zero), Ok(zero));
+}
+
+#[test]
+fn matches_sorted() {
+    let lines = [
+        "eightwo",
+        "oneight",
+        "twone",
+        "fiveight3",
+        "abc",
+        "",
+        "é9éseven",
+        "nineninenin",
+        "sevenineightwoneight",
+    ];
+    let example = include_str!("../../input/day01/example02.txt");
+    for line in example.lines().chain(lines) {
+        assert_eq!(find_matches(line), find_matches_sorted(line), "{line}");
+    }
+}
+
+#[test]
+fn validate_lines() {
+    let report = Day01::validate("1abc2\nseven\nabc\ntwo3\n");
+    assert_eq!(report.to_string(), "2 of 4 lines accepted, 2 rejected");
+    let errors: Vec<String> = report.errors.iter().map(Error::to_string).collect();
+    assert_eq!(
+        errors,
+        [
+            "day 01, line 2, column 1, at \"seven\": only digit words, which part 1 doesn't read",
+            "day 01, line 3, column 1, at \"abc\": no digits",
+        ]
+    );
+    let lines = Day01::parse("seven\n").unwrap();
+    assert!(Day01::part1(&lines).is_err());
+    assert_eq!(Day01::part2(&lines), Ok(77));
+}
+
+#[test]
+fn unicode_digits() {
+    // Arabic-Indic, full-width and Devanagari digits among Latin ones, with
+    // the values of part 1 in ASCII and Unicode, then part 2 in Unicode
+    let lines = [
+        ("abc٣def٧", None, Some(37), Some(37)),
+        ("x１y2z", Some(22), Some(12), Some(12)),
+        ("seven ९ one", None, Some(99), Some(71)),
+        ("٤two", None, Some(44), Some(42)),
+        ("two٤", None, Some(44), Some(24)),
+        ("३eightwo", None, Some(33), Some(32)),
+        ("ⅷ ²", None, None, None),
+    ];
+    for (line, ascii, unicode, words) in lines {
+        let value = |digits: Option<CalibrationDigits>| digits.map(CalibrationDigits::combine);
+        assert_eq!(value(find_digits(line)), ascii, "{line}");
+        assert_eq!(
+            value(find_digits_with(line, Digits::Unicode)),
+            unicode,
+            "{line}"
+        );
+        assert_eq!(
+            value(find_matches_with(line, Digits::Unicode)),
+            words,
+            "{line}"
+        );
+        let sorted = Matches::find_with(line, Digits::Unicode).calibration_digits();
+        assert_eq!(value(sorted), words, "{line}");
+    }
+    let options = Options {
+        digits: Digits::Unicode,
+        ..Options::default()
+    };
+    let input = "٣٤\n１2\n";
+    assert_eq!(
+        solve(input, Policy::Strict, Part::One, options).map(|c| c.sum),
+        Ok(46)
+    );
+    // without them the first line has no digits
+    let error = solve(input, Policy::Strict, Part::One, Options::default()).unwrap_err();
+    assert_eq!(error.line(), Some(1));
+}
+
+#[test]
+fn streams() {
+    let example = include_str!("../../input/day01/example02.txt");
+    assert_eq!(
+        Day01::stream(example.as_bytes(), Policy::Strict, Part::Two).ok(),
+        Some(281)
+    );
+    let Err(StreamError::Solve(error)) =
+        Day01::stream("1abc2\npqrstu\n\n".as_bytes(), Policy::Lenient, Part::One)
+    else {
+        panic!("a line has no digits");
+    };
+    assert_eq!(error.line(), Some(2));
+    // the blank line is rejected before the line without digits
+    let Err(StreamError::Solve(error)) =
+        Day01::stream("1abc2\npqrstu\n\n".as_bytes(), Policy::Strict, Part::One)
+    else {
+        panic!("a line is blank");
+    };
+    assert_eq!(error.line(), Some(3));
+}
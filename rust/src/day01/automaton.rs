@@ -0,0 +1,118 @@
+//! Aho–Corasick automata over the digits and digit words, finding the
+//! first and last digit of a line by searching from each end.
+
+use std::cmp::Reverse;
+use std::sync::OnceLock;
+
+use aho_corasick::{AhoCorasick, AhoCorasickBuilder, AhoCorasickKind, BuildError, MatchKind};
+
+use super::REPLACEMENTS;
+
+/// The automata for reading a line forwards, and backwards with the
+/// patterns reversed.
+#[derive(Debug)]
+pub struct Matcher {
+    /// Finds the longest of the matches starting first.
+    forwards: AhoCorasick,
+    /// Finds every match in the reversed line, in the order they end.
+    backwards: AhoCorasick,
+    /// The index of every pattern of the automata, which leave out empty
+    /// patterns.
+    patterns: Vec<usize>,
+}
+
+impl Matcher {
+    /// Of patterns that are the same only the first matches, and empty
+    /// patterns never do.
+    pub fn new(patterns: &[Vec<u8>]) -> Result<Self, BuildError> {
+        let (indices, patterns): (Vec<usize>, Vec<&Vec<u8>>) = patterns
+            .iter()
+            .enumerate()
+            .filter(|(_, pattern)| !pattern.is_empty())
+            .unzip();
+        let reversed = patterns
+            .iter()
+            .map(|pattern| pattern.iter().rev().copied().collect::<Vec<u8>>());
+        // a DFA would take quadratic time to build for long words
+        let builder = |kind| {
+            let mut builder = AhoCorasickBuilder::new();
+            builder
+                .kind(Some(AhoCorasickKind::ContiguousNFA))
+                .match_kind(kind);
+            builder
+        };
+        Ok(Matcher {
+            forwards: builder(MatchKind::LeftmostLongest).build(&patterns)?,
+            backwards: builder(MatchKind::Standard).build(reversed)?,
+            patterns: indices,
+        })
+    }
+
+    /// The digits `0` to `9`, followed by the digit words of part 2.
+    pub fn digits() -> &'static Matcher {
+        static DIGITS: OnceLock<Matcher> = OnceLock::new();
+        DIGITS.get_or_init(|| {
+            let digits = (b'0'..=b'9').map(|digit| vec![digit]);
+            let words = REPLACEMENTS
+                .iter()
+                .map(|(word, _)| word.as_bytes().to_vec());
+            Matcher::new(&digits.chain(words).collect::<Vec<_>>()).expect("the digits fit")
+        })
+    }
+
+    /// The patterns of the matches starting first and last (the longest
+    /// ones if several start there), `None` if there are none.
+    pub fn find(&self, line: &str) -> Option<(usize, usize)> {
+        let ((_, first), (_, last)) = self.find_at(line)?;
+        Some((first, last))
+    }
+
+    /// Like [`Matcher::find`], with the byte offsets where the matches
+    /// start.
+    pub fn find_at(&self, line: &str) -> Option<((usize, usize), (usize, usize))> {
+        let first = self.forwards.find(line)?;
+        // the match starting last is the first to end in the reversed line,
+        // and the longest of those is the longest starting there
+        let reversed: Vec<u8> = line.bytes().rev().collect();
+        let mut ending = self.backwards.find_overlapping_iter(&reversed).peekable();
+        let end = ending.peek()?.end();
+        let last = ending
+            .take_while(|found| found.end() == end)
+            .max_by_key(|found| (found.len(), Reverse(found.pattern())))?;
+        Some((
+            (first.start(), self.patterns[first.pattern().as_usize()]),
+            (
+                line.len() - last.end(),
+                self.patterns[last.pattern().as_usize()],
+            ),
+        ))
+    }
+}
+
+#[test]
+fn overlapping() {
+    let matcher = Matcher::digits();
+    for (line, first, last) in [
+        ("eightwo", 17, 11),
+        ("oneight", 10, 17),
+        ("xtwone3four", 11, 13),
+        ("sevenine", 16, 18),
+        ("nineight7", 18, 7),
+        ("threeeight", 12, 17),
+        ("ffive", 14, 14),
+        ("0", 0, 0),
+    ] {
+        assert_eq!(matcher.find(line), Some((first, last)), "{line}");
+    }
+    assert_eq!(matcher.find("onx twe"), None);
+    // matches are ordered by where they start, not where they end
+    let matcher = Matcher::new(&[b"abcd".to_vec(), b"bc".to_vec()]).unwrap();
+    assert_eq!(matcher.find("xabcdx"), Some((0, 1)));
+    assert_eq!(matcher.find("xabcx"), Some((1, 1)));
+    assert_eq!(matcher.find_at("xabcdx"), Some(((1, 0), (2, 1))));
+    // the first of patterns that are the same, and never empty ones
+    let matcher = Matcher::new(&[vec![], b"ab".to_vec(), b"b".to_vec(), b"ab".to_vec()]).unwrap();
+    assert_eq!(matcher.find_at("xabx"), Some(((1, 1), (2, 2))));
+    assert_eq!(matcher.find_at("xbx"), Some(((1, 2), (1, 2))));
+    assert_eq!(matcher.find("x"), None);
+}
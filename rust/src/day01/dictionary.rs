@@ -0,0 +1,302 @@
+//! Digit words loaded at runtime, for calibrating lines written in other
+//! languages than the English of part 2.
+
+use std::borrow::Cow;
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use aho_corasick::BuildError;
+use serde::Deserialize;
+
+use super::{calibrate_with, unicode, Calibration, Day01, Digits, Matcher, Options, REPLACEMENTS};
+use crate::error::{Error, Span};
+use crate::policy::{Layout, Policy};
+use crate::solution::Solution;
+
+/// The most digits a word may stand for, so that the values of two words
+/// make a `u64`.
+const MAX_DIGITS: usize = 9;
+
+/// The values of the digits of every script.
+const VALUES: [&str; 10] = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+
+/// Words and the digits they stand for, which make up the calibration
+/// value of a line along with the digits themselves.
+#[derive(Debug)]
+pub struct Dictionary {
+    matcher: Matcher,
+    /// The value of every pattern of the matcher.
+    values: Vec<String>,
+    /// Whether `0` to `9` are patterns, and other digits may be too.
+    digits: bool,
+    ignore_case: bool,
+}
+
+impl Dictionary {
+    /// `words` are lowercase if the case of lines is ignored, and distinct.
+    fn new(
+        words: Vec<(String, String)>,
+        digits: bool,
+        ignore_case: bool,
+    ) -> Result<Self, BuildError> {
+        let numerals = ('0'..='9')
+            .filter(|_| digits)
+            .map(|digit| (digit.to_string(), digit.to_string()));
+        let (patterns, values): (Vec<Vec<u8>>, Vec<String>) = words
+            .into_iter()
+            .chain(numerals)
+            .map(|(word, value)| (word.into_bytes(), value))
+            .unzip();
+        Ok(Dictionary {
+            matcher: Matcher::new(&patterns)?,
+            values,
+            digits,
+            ignore_case,
+        })
+    }
+
+    /// The values of the words (or digits) starting first and last in a
+    /// line, `None` if there are none. Words may overlap, like in
+    /// `eightwo`. The digits of other scripts only count if `0` to `9` do.
+    pub fn find(&self, line: &str, digits: Digits) -> Option<(&str, &str)> {
+        let line = match self.ignore_case {
+            true => Cow::Owned(line.to_lowercase()),
+            false => Cow::Borrowed(line),
+        };
+        let found = self.matcher.find_at(&line).map(|((first, f), (last, l))| {
+            (
+                (first, self.values[f].as_str()),
+                (last, self.values[l].as_str()),
+            )
+        });
+        let digits = if self.digits { digits } else { Digits::Ascii };
+        digits.first_and_last(&line, found, |digit| {
+            VALUES[unicode::digit(digit).expect("a decimal digit") as usize]
+        })
+    }
+
+    /// The values of the first and last words written one after the other,
+    /// like 117 for words worth `1` and `17`.
+    pub fn calibration_value(&self, line: &str, digits: Digits) -> Option<u64> {
+        let (first, last) = self.find(line, digits)?;
+        // values have at most `MAX_DIGITS` digits
+        Some(format!("{first}{last}").parse().unwrap())
+    }
+
+    /// Part 2 with the dictionary's words, on an input prepared under a
+    /// policy.
+    pub fn solve(
+        &self,
+        input: &str,
+        policy: Policy,
+        options: Options,
+    ) -> Result<Calibration, Error> {
+        let prepared = policy.prepare(Day01::DAY, Layout::Lines, input, Day01::tidy)?;
+        calibrate_with(prepared.text.lines(), options.no_digits, |index, line| {
+            self.calibration_value(line, options.digits).ok_or_else(|| {
+                let span = Span::line(index, line);
+                prepared.locate(Error::invalid(
+                    Day01::DAY,
+                    Some(span),
+                    "no digits or digit words",
+                ))
+            })
+        })
+    }
+}
+
+/// The digits and English digit words of part 2.
+impl Default for Dictionary {
+    fn default() -> Self {
+        let words = REPLACEMENTS
+            .iter()
+            .map(|(word, value)| (word.to_string(), value.to_string()));
+        Dictionary::new(words.collect(), true, false).expect("the English words fit")
+    }
+}
+
+/// A dictionary as written in TOML.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct File {
+    digits: Option<bool>,
+    ignore_case: Option<bool>,
+    /// The words of every language, and the digits they stand for.
+    #[serde(default)]
+    words: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+/// Reads a dictionary from TOML like
+///
+/// ```toml
+/// # whether `0` to `9` are digits too (the default)
+/// digits = true
+/// # match words whatever their case (not the default)
+/// ignore_case = true
+///
+/// [words.fr]
+/// "zéro" = "0"
+/// un = "1"
+/// dix-sept = "17"
+/// ```
+///
+/// where every table under `words` lists the words of a language and the
+/// digits they stand for, at most nine. A word in several languages must
+/// stand for the same digits in each.
+impl FromStr for Dictionary {
+    type Err = Error;
+
+    fn from_str(toml: &str) -> Result<Self, Error> {
+        let file: File = toml::from_str(toml).map_err(|e| Error::toml(Day01::DAY, toml, &e))?;
+        let invalid = |message: String| Error::invalid(Day01::DAY, None, message);
+        let ignore_case = file.ignore_case.unwrap_or(false);
+        // the same word may be in several languages, standing for the same
+        let mut words = BTreeMap::new();
+        for (word, value) in file.words.into_values().flatten() {
+            let word = match ignore_case {
+                true => word.to_lowercase(),
+                false => word,
+            };
+            if word.is_empty() {
+                return Err(invalid("empty word".to_string()));
+            }
+            if value.is_empty()
+                || value.len() > MAX_DIGITS
+                || !value.bytes().all(|b| b.is_ascii_digit())
+            {
+                let message =
+                    format!("words stand for one to nine digits, like \"17\", not {value:?}");
+                return Err(invalid(message));
+            }
+            match words.entry(word) {
+                Entry::Vacant(entry) => {
+                    entry.insert(value);
+                }
+                Entry::Occupied(entry) if *entry.get() != value => {
+                    let (word, other) = (entry.key(), entry.get());
+                    return Err(invalid(format!(
+                        "{word:?} stands for both {other:?} and {value:?}"
+                    )));
+                }
+                Entry::Occupied(_) => {}
+            }
+        }
+        let digits = file.digits.unwrap_or(true);
+        Dictionary::new(words.into_iter().collect(), digits, ignore_case)
+            .map_err(|e| invalid(format!("can't match the words: {e}")))
+    }
+}
+
+#[test]
+fn english() {
+    let english = Dictionary::default();
+    let example = include_str!("../../../input/day01/example02.txt");
+    assert_eq!(
+        english
+            .solve(example, Policy::Strict, Options::default())
+            .map(|c| c.sum),
+        Ok(281)
+    );
+    for line in example.lines().chain(["eightwo", "oneight", "0"]) {
+        let (first, last) = english.find(line, Digits::Ascii).unwrap();
+        let super::CalibrationDigits(first_digit, last_digit) = super::find_matches(line).unwrap();
+        assert_eq!(
+            (first, last),
+            (&*first_digit.to_string(), &*last_digit.to_string())
+        );
+    }
+}
+
+#[test]
+fn multilingual() {
+    let dictionary: Dictionary = r#"
+# les mots
+digits = false
+ignore_case = true
+
+[words]
+es = { uno = "1", cero = "0" }
+
+[words.fr]
+"zéro" = "0"  # with an accent
+un = "1"
+'dix-sept' = "17"
+"\u00C9TRANGE" = """99"""
+# the same word as `un` once lowercased, and as in Italian
+Un = "1"
+
+[words.it]
+uno = '1'
+"#
+    .parse()
+    .unwrap();
+    let value = |line| dictionary.calibration_value(line, Digits::Unicode);
+    assert_eq!(
+        dictionary.find("Dix-Sept et UN", Digits::Ascii),
+        Some(("17", "1"))
+    );
+    assert_eq!(value("zÉro, 5, étrange"), Some(99));
+    assert_eq!(value("dix-sept"), Some(1717));
+    assert_eq!(value("cero uno"), Some(1));
+    assert_eq!(value("5 deux"), None);
+    // without `0` to `9`, digits of other scripts don't count either
+    assert_eq!(value("٣ un"), Some(11));
+    let error = dictionary
+        .solve("un\n\ntrois\n", Policy::Lenient, Options::default())
+        .unwrap_err();
+    assert_eq!(error.line(), Some(3));
+    let arabic: Dictionary = "[words.ar]\n\"واحد\" = \"1\"\n".parse().unwrap();
+    assert_eq!(
+        arabic.calibration_value("واحد و٣", Digits::Unicode),
+        Some(13)
+    );
+    assert_eq!(
+        arabic.calibration_value("٧ واحد", Digits::Unicode),
+        Some(71)
+    );
+    assert_eq!(arabic.calibration_value("٧ واحد", Digits::Ascii), Some(11));
+}
+
+#[test]
+fn invalid_dictionaries() {
+    let errors = [
+        ("[words.fr]\nun = 1\n", Some(2), "invalid type"),
+        ("[words]\nun = \"1\"\n", Some(2), "invalid type"),
+        ("digits = \"yes\"\n", Some(1), "invalid type"),
+        ("colour = true\n", Some(1), "unknown field"),
+        (
+            "[words.fr]\nun = \"1\"\nun = \"1\"\n",
+            Some(3),
+            "duplicate key",
+        ),
+        (
+            "[words.fr]\nun = \"\\uD800\"\n",
+            Some(2),
+            "hex code: value is out of range",
+        ),
+        ("[words.fr]\nun = \"1234567890\"\n", None, "words stand for"),
+        ("[words.fr]\n\"\" = \"1\"\n", None, "empty word"),
+        (
+            "ignore_case = true\n[words.fr]\nun = \"1\"\nUn = \"2\"\n",
+            None,
+            "\"un\" stands for both",
+        ),
+        (
+            "[words.en]\nun = \"1\"\n[words.fr]\nun = \"2\"\n",
+            None,
+            "stands for both",
+        ),
+    ];
+    for (toml, line, message) in errors {
+        let error = toml.parse::<Dictionary>().unwrap_err();
+        assert_eq!(error.line(), line, "{toml}");
+        assert!(error.to_string().contains(message), "{toml}: {error}");
+    }
+    // but words of any length are fine
+    let long = |letter: char| letter.to_string().repeat(40_000);
+    let toml = format!("[words.en]\n{} = \"1\"\n{} = \"2\"\n", long('a'), long('b'));
+    let dictionary = toml.parse::<Dictionary>().unwrap();
+    let line = format!("x{}x{}x", long('b'), long('a'));
+    assert_eq!(dictionary.calibration_value(&line, Digits::Ascii), Some(21));
+}
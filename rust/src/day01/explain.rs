@@ -0,0 +1,167 @@
+//! What `run 1 --explain` shows of every line: each digit and digit word
+//! found, the first and last of them and the calibration value they make.
+
+use std::fmt::Write;
+
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use super::{find_matches_with, unicode, Day01, Digits, Match, Matches};
+use crate::error::Error;
+use crate::policy::{Layout, Policy};
+use crate::solution::Solution;
+
+/// The first and last matches in the terminal: bold green and bold cyan.
+const STYLES: [&str; 2] = ["\x1b[1;32m", "\x1b[1;36m"];
+const RESET: &str = "\x1b[0m";
+
+/// The line with the first and last matches highlighted in `STYLES`.
+fn highlight(line: &str, chosen: [&Match; 2]) -> String {
+    let mut highlighted = String::new();
+    let mut current = None;
+    for (index, c) in line.char_indices() {
+        let style = chosen
+            .iter()
+            .position(|m| (m.index..m.index + m.length).contains(&index));
+        if style != current {
+            highlighted += style.map_or(RESET, |style| STYLES[style]);
+            current = style;
+        }
+        highlighted.push(c);
+    }
+    if current.is_some() {
+        highlighted += RESET;
+    }
+    highlighted
+}
+
+/// `^` under the first match and `~` under the last, as wide as the
+/// characters are in a terminal.
+fn underline(line: &str, chosen: [&Match; 2]) -> String {
+    let underline: String = line
+        .char_indices()
+        .map(|(index, c)| {
+            let mark = chosen
+                .iter()
+                .position(|m| (m.index..m.index + m.length).contains(&index));
+            let mark = mark.map_or(' ', |mark| ['^', '~'][mark]);
+            // combining marks take no room of their own
+            mark.to_string().repeat(c.width().unwrap_or(0))
+        })
+        .collect();
+    underline.trim_end().to_string()
+}
+
+/// Explains the (0-based) `index`th line of an input: every match by where
+/// it starts, with the first and last highlighted in color, or underlined
+/// without.
+pub fn explain_line(index: usize, line: &str, digits: Digits, color: bool) -> String {
+    let mut matches = Matches::find_with(line, digits);
+    matches.0.sort_by_key(|m| m.index);
+    let label = format!("line {}: ", index + 1);
+    let mut explained = String::new();
+    let (Some(first), Some(last)) = (matches.0.first(), matches.0.last()) else {
+        writeln!(explained, "{label}{line}").unwrap();
+        writeln!(explained, "  no digits or digit words").unwrap();
+        return explained;
+    };
+    if color {
+        writeln!(explained, "{label}{}", highlight(line, [first, last])).unwrap();
+    } else {
+        writeln!(explained, "{label}{line}").unwrap();
+        let indent = " ".repeat(label.len());
+        writeln!(explained, "{indent}{}", underline(line, [first, last])).unwrap();
+    }
+    for (position, m) in matches.0.iter().enumerate() {
+        let token = format!("{:?}", &line[m.index..m.index + m.length]);
+        // padded by width, so that wide digits line up too
+        let padding = " ".repeat(8usize.saturating_sub(token.width()));
+        let value = unicode::digit(m.value).expect("a decimal digit");
+        let chosen = match (position == 0, position + 1 == matches.0.len()) {
+            (true, true) => "  first and last",
+            (true, false) => "  first",
+            (false, true) => "  last",
+            (false, false) => "",
+        };
+        writeln!(
+            explained,
+            "  at {:<4} {token}{padding} {value}{chosen}",
+            m.index
+        )
+        .unwrap();
+    }
+    // what part 2 actually uses
+    let value = find_matches_with(line, digits).map(|digits| digits.combine());
+    writeln!(
+        explained,
+        "  calibration value: {}",
+        value.unwrap_or_default()
+    )
+    .unwrap();
+    explained
+}
+
+/// Explains every line of an input prepared under a policy, numbered as in
+/// the input.
+pub fn explain(input: &str, policy: Policy, digits: Digits, color: bool) -> Result<String, Error> {
+    let prepared = policy.prepare(Day01::DAY, Layout::Lines, input, Day01::tidy)?;
+    let explained = prepared
+        .text
+        .lines()
+        .enumerate()
+        .map(|(index, line)| explain_line(prepared.origin(index), line, digits, color))
+        .collect();
+    Ok(explained)
+}
+
+#[test]
+fn explained() {
+    assert_eq!(
+        explain_line(0, "xtwone3four", Digits::Ascii, false),
+        "line 1: xtwone3four
+         ^^^   ~~~~
+  at 1    \"two\"    2  first
+  at 3    \"one\"    1
+  at 6    \"3\"      3
+  at 7    \"four\"   4  last
+  calibration value: 24
+"
+    );
+    assert_eq!(
+        explain_line(2, "eightwo", Digits::Ascii, true),
+        "line 3: \x1b[1;32meight\x1b[1;36mwo\x1b[0m
+  at 0    \"eight\"  8  first
+  at 4    \"two\"    2  last
+  calibration value: 82
+"
+    );
+    assert_eq!(
+        explain("7\n\nabc\n", Policy::Lenient, Digits::Ascii, false),
+        Ok("line 1: 7
+        ^
+  at 0    \"7\"      7  first and last
+  calibration value: 77
+line 3: abc
+  no digits or digit words
+"
+        .to_string())
+    );
+    assert_eq!(
+        explain_line(0, "٣two", Digits::Unicode, false),
+        "line 1: ٣two
+        ^~~~
+  at 0    \"٣\"      3  first
+  at 2    \"two\"    2  last
+  calibration value: 32
+"
+    );
+    // full-width digits take two columns
+    assert_eq!(
+        explain_line(0, "１x９", Digits::Unicode, false),
+        "line 1: １x９
+        ^^ ~~
+  at 0    \"１\"     1  first
+  at 4    \"９\"     9  last
+  calibration value: 19
+"
+    );
+}
@@ -0,0 +1,56 @@
+//! The decimal digits of every script (Unicode's `Nd`), like `٣`, `３` or
+//! `३`, and their values.
+
+/// The version of Unicode that [`ZEROS`] lists the decimal digits of.
+pub const UNICODE_VERSION: (u8, u8, u8) = (17, 0, 0);
+
+/// The first code point (the zero) of every run of ten decimal digits, as of
+/// [`UNICODE_VERSION`]. Decimal digits always come in runs of ten, from 0 to
+/// 9.
+const ZEROS: [u32; 77] = [
+    0x0030, 0x0660, 0x06F0, 0x07C0, 0x0966, 0x09E6, 0x0A66, 0x0AE6, 0x0B66, 0x0BE6, 0x0C66, 0x0CE6,
+    0x0D66, 0x0DE6, 0x0E50, 0x0ED0, 0x0F20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946, 0x19D0, 0x1A80,
+    0x1A90, 0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900, 0xA9D0, 0xA9F0, 0xAA50, 0xABF0,
+    0xFF10, 0x104A0, 0x10D30, 0x10D40, 0x11066, 0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450,
+    0x114D0, 0x11650, 0x116C0, 0x116D0, 0x116DA, 0x11730, 0x118E0, 0x11950, 0x11BF0, 0x11C50,
+    0x11D50, 0x11DA0, 0x11DE0, 0x11F50, 0x16130, 0x16A60, 0x16AC0, 0x16B50, 0x16D70, 0x1CCF0,
+    0x1D7CE, 0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140, 0x1E2F0, 0x1E4F0, 0x1E5F1, 0x1E950,
+    0x1FBF0,
+];
+
+/// The value of a decimal digit of any script, `None` for other
+/// characters.
+pub fn digit(c: char) -> Option<u32> {
+    if c.is_ascii() {
+        return c.to_digit(10);
+    }
+    let runs = ZEROS.partition_point(|&zero| zero <= u32::from(c));
+    let value = u32::from(c) - ZEROS[runs.checked_sub(1)?];
+    (value < 10).then_some(value)
+}
+
+#[test]
+fn decimal_numbers() {
+    // runs of ten, in order, some right after another
+    for pair in ZEROS.windows(2) {
+        assert!(pair[0] + 10 <= pair[1], "{:X}", pair[1]);
+    }
+    for zero in ZEROS {
+        let digits: Vec<char> = (zero..zero + 10).filter_map(char::from_u32).collect();
+        let values = digits.iter().map(|&c| digit(c));
+        assert!(values.eq((0..10).map(Some)), "{zero:X}");
+        // digits are numbers in any Unicode since theirs
+        if char::UNICODE_VERSION >= UNICODE_VERSION {
+            assert!(digits.iter().all(|c| c.is_numeric()), "{zero:X}");
+        }
+    }
+    // the runs added by the latest versions: Ol Onal (16.0), Tolong Siki (17.0)
+    assert_eq!(digit('\u{1E5F1}'), Some(0));
+    assert_eq!(digit('\u{11DE9}'), Some(9));
+    assert_eq!(digit('٣'), Some(3));
+    assert_eq!(digit('７'), Some(7));
+    assert_eq!(digit('९'), Some(9));
+    assert_eq!(digit('Ⅳ'), None);
+    assert_eq!(digit('²'), None);
+    assert_eq!(digit('①'), None);
+}
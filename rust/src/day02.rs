@@ -0,0 +1,422 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::io::BufRead;
+
+use pest::iterators::Pair;
+use pest::Parser;
+use pest_derive::Parser;
+use serde::{Deserialize, Serialize};
+
+use crate::backend::Backend;
+use crate::error::{Error, Span};
+use crate::policy::Policy;
+use crate::solution::{Part, Solution};
+use crate::stream::{self, StreamError};
+use crate::validate::Report;
+
+mod nom;
+
+#[derive(Parser)]
+#[grammar = "day02.pest"]
+struct GameParser;
+
+fn hint(rule: Rule) -> Option<&'static str> {
+    match rule {
+        Rule::game => Some("a game looks like `Game 23: 1 blue, 2 green; 7 red`"),
+        Rule::id => Some("games start with their id, like `Game 23: `"),
+        Rule::count => Some("cubes are shown as a count and a color, like `3 blue`"),
+        Rule::color => Some("colors are `red`, `green` or `blue`"),
+        Rule::show | Rule::reveal => Some("cubes are separated by `, ` and reveals by `; `"),
+        Rule::EOI => Some("a game ends with its last cube, without a trailing separator"),
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Cubes {
+    Red(u64),
+    Green(u64),
+    Blue(u64),
+}
+
+impl Cubes {
+    const NUM_RED_CUBES: u64 = 12;
+    const NUM_GREEN_CUBES: u64 = 13;
+    const NUM_BLUE_CUBES: u64 = 14;
+
+    pub fn invalid(&self) -> bool {
+        match self {
+            Self::Red(count) => count > &Self::NUM_RED_CUBES,
+            Self::Green(count) => count > &Self::NUM_GREEN_CUBES,
+            Self::Blue(count) => count > &Self::NUM_BLUE_CUBES,
+        }
+    }
+}
+
+impl fmt::Display for Cubes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Red(count) => write!(f, "{count} red"),
+            Self::Green(count) => write!(f, "{count} green"),
+            Self::Blue(count) => write!(f, "{count} blue"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Reveal(pub Vec<Cubes>);
+
+impl fmt::Display for Reveal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, cubes) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{cubes}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct GameRecord {
+    pub id: u64,
+    pub reveals: Vec<Reveal>,
+}
+
+impl GameRecord {
+    pub fn invalid(&self) -> bool {
+        self.reveals
+            .iter()
+            .any(|reveal| reveal.0.iter().any(Cubes::invalid))
+    }
+}
+
+/// A game as a line of puzzle input (without the line break), which parses
+/// back to the same record.
+impl fmt::Display for GameRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Game {}: ", self.id)?;
+        for (index, reveal) in self.reveals.iter().enumerate() {
+            if index > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{reveal}")?;
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<&str> for GameRecord {
+    type Error = Error;
+
+    /// Errors point into `line`, as the first line of the input.
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let day = Day02::DAY;
+        let number = |pair: Pair<Rule>| {
+            pair.as_str()
+                .parse::<u64>()
+                .map_err(|e| Error::syntax(day, Span::pair(&pair), e.to_string()))
+        };
+        let mut game = GameParser::parse(Rule::game, line)
+            .map_err(|e| Error::pest(day, &e, hint))?
+            .next()
+            .expect("a parsed game")
+            .into_inner();
+        let id = number(game.next().expect("a game has an id"))?;
+        let mut reveals = Vec::new();
+        for reveal in game.filter(|pair| pair.as_rule() == Rule::reveal) {
+            let mut cubes = Vec::new();
+            for show in reveal.into_inner() {
+                let mut children = show.into_inner();
+                let count = number(children.next().expect("a show has a count"))?;
+                let color = children.next().expect("a show has a color");
+                let cube = match color.as_str() {
+                    "red" => Cubes::Red(count),
+                    "green" => Cubes::Green(count),
+                    "blue" => Cubes::Blue(count),
+                    _ => return Err(Error::syntax(day, Span::pair(&color), "invalid color")),
+                };
+                cubes.push(cube);
+            }
+            reveals.push(Reveal(cubes));
+        }
+        Ok(Self { id, reveals })
+    }
+}
+
+#[derive(Debug)]
+pub struct FewestCubes {
+    pub red: u64,
+    pub green: u64,
+    pub blue: u64,
+}
+
+impl From<&GameRecord> for FewestCubes {
+    fn from(game: &GameRecord) -> Self {
+        let mut red: u64 = 0;
+        let mut green: u64 = 0;
+        let mut blue: u64 = 0;
+        for reveal in &game.reveals {
+            for cube in &reveal.0 {
+                match *cube {
+                    Cubes::Red(count) => red = red.max(count),
+                    Cubes::Green(count) => green = green.max(count),
+                    Cubes::Blue(count) => blue = blue.max(count),
+                }
+            }
+        }
+        FewestCubes { red, green, blue }
+    }
+}
+
+impl FewestCubes {
+    /// `None` if the power overflows.
+    pub fn power(self) -> Option<u64> {
+        self.red.checked_mul(self.green)?.checked_mul(self.blue)
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<GameRecord>;
+    type Answer = u64;
+
+    /// Drops separators with nothing after them, like in `3 blue, ; 1 red;`.
+    fn tidy(line: &str) -> Cow<'_, str> {
+        let Some((game, reveals)) = line.split_once(": ") else {
+            return Cow::Borrowed(line);
+        };
+        let reveals: Vec<String> = reveals
+            .split(';')
+            .map(|reveal| {
+                let shows: Vec<&str> = reveal
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|show| !show.is_empty())
+                    .collect();
+                shows.join(", ")
+            })
+            .filter(|reveal| !reveal.is_empty())
+            .collect();
+        Cow::Owned(format!("{game}: {}", reveals.join("; ")))
+    }
+
+    const BACKENDS: &'static [Backend] = &[Backend::Pest, Backend::Nom];
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| GameRecord::try_from(line).map_err(|e| e.on_line(index)))
+            .collect()
+    }
+
+    fn parse_with(backend: Backend, input: &str) -> Result<Self::Input, Error> {
+        match backend {
+            Backend::Pest => Self::parse(input),
+            Backend::Nom => input
+                .lines()
+                .enumerate()
+                .map(|(index, line)| self::nom::game(line).map_err(|e| e.on_line(index)))
+                .collect(),
+        }
+    }
+
+    fn validate(input: &str) -> Report {
+        Report::lines("games", input, |line| GameRecord::try_from(line))
+    }
+
+    fn part1(games: &Self::Input) -> Result<u64, Error> {
+        games
+            .iter()
+            .filter_map(|record| {
+                if record.invalid() {
+                    None
+                } else {
+                    Some(record.id)
+                }
+            })
+            .try_fold(0u64, u64::checked_add)
+            .ok_or(Error::overflow(Self::DAY))
+    }
+
+    fn part2(games: &Self::Input) -> Result<u64, Error> {
+        games
+            .iter()
+            .try_fold(0u64, |sum, game| {
+                sum.checked_add(FewestCubes::from(game).power()?)
+            })
+            .ok_or(Error::overflow(Self::DAY))
+    }
+
+    fn stream(reader: impl BufRead, policy: Policy, part: Part) -> Result<u64, StreamError> {
+        // `None` once the sum overflows, which is reported after every game
+        // has parsed
+        let mut sum = Some(0u64);
+        stream::lines(reader, Self::DAY, policy, Self::tidy, |index, line| {
+            let game = GameRecord::try_from(line).map_err(|e| e.on_line(index))?;
+            let value = match part {
+                Part::One if game.invalid() => Some(0),
+                Part::One => Some(game.id),
+                Part::Two => FewestCubes::from(&game).power(),
+            };
+            sum = sum
+                .zip(value)
+                .and_then(|(sum, value)| sum.checked_add(value));
+            Ok(())
+        })?;
+        Ok(sum.ok_or(Error::overflow(Self::DAY))?)
+    }
+}
+
+#[test]
+fn parse_line() {
+    let line = "Game 123: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+    let record = GameRecord::try_from(line).unwrap();
+
+    use Cubes::{Blue, Green, Red};
+    assert_eq!(
+        record,
+        GameRecord {
+            id: 123,
+            reveals: vec![
+                Reveal(vec![Blue(3), Red(4)]),
+                Reveal(vec![Red(1), Green(2), Blue(6)]),
+                Reveal(vec![Green(2)])
+            ]
+        }
+    );
+}
+
+#[test]
+fn round_trip() {
+    let example = include_str!("../../input/day02/example01.txt");
+    for line in example.lines() {
+        let record = GameRecord::try_from(line).unwrap();
+        assert_eq!(record.to_string(), line);
+    }
+}
+
+#[test]
+fn example01() {
+    let example = include_str!("../../input/day02/example01.txt");
+    let games = Day02::parse(example).unwrap();
+    assert_eq!(Day02::part1(&games), Ok(8));
+}
+
+#[test]
+fn example02() {
+    let example = include_str!("../../input/day02/example02.txt");
+    let games = Day02::parse(example).unwrap();
+    assert_eq!(Day02::part2(&games), Ok(2286));
+}
+
+#[test]
+fn validate_all_lines() {
+    let example = "Game 1: 3 blue\nGame 2: 4 purple\nGame 3: 1 red\nGame x: 2 green\n";
+    let report = Day02::validate(example);
+    assert_eq!(report.to_string(), "2 of 4 games accepted, 2 rejected");
+    let lines: Vec<usize> = report
+        .errors
+        .iter()
+        .filter_map(|e| match e {
+            Error::Syntax { span, .. } => Some(span.line),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(lines, vec![2, 4]);
+}
+
+#[test]
+fn dangling_separators() {
+    use crate::policy::Policy;
+    use crate::solution::{Day, Part};
+
+    let line = "Game 1: 3 blue, ; 4 red;";
+    assert!(matches!(
+        Day02.parse_input(line, Policy::Strict),
+        Err(Error::Syntax { .. })
+    ));
+    let games = Day02.parse_input(line, Policy::Lenient).unwrap();
+    assert_eq!(Day02.solve(&games, Part::One), Ok("1".to_string()));
+}
+
+#[test]
+fn bad_line() {
+    let example = "Game 1: 3 blue\nGame 2: 4 purple\n";
+    let Err(Error::Syntax {
+        day, span, hint, ..
+    }) = Day02::parse(example)
+    else {
+        panic!("purple cubes should not parse");
+    };
+    assert_eq!(day, 2);
+    assert_eq!(hint, Some("colors are `red`, `green` or `blue`"));
+    assert_eq!(
+        (span.line, span.column, span.text.as_str()),
+        (2, 11, "purple")
+    );
+}
+
+#[test]
+fn overflow() {
+    let games = Day02::parse("Game 1: 9999999999 red, 9999999999 green, 1 blue\n").unwrap();
+    assert_eq!(Day02::part1(&games), Ok(0));
+    assert_eq!(Day02::part2(&games), Err(Error::overflow(2)));
+}
+
+#[test]
+fn nom_matches_pest() {
+    let lines = [
+        "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+        "Game 2: 4 purple",
+        "Game 3: 1 red, 2 purple",
+        "Game 4: 1 red; 2 blue,",
+        "Game 5: 1 red;",
+        "Game x: 1 red",
+        "Game 6 1 red",
+        "Game 7: 1red",
+        "Game 8: 1 red  ",
+        "game 9: 1 red",
+        "Game 10: 99999999999999999999 red",
+        "",
+    ];
+    let example = include_str!("../../input/day02/example01.txt");
+    for line in example.lines().chain(lines) {
+        crate::backend::assert_agree::<Day02>(line);
+    }
+    let Err(Error::Syntax { span, hint, .. }) =
+        Day02::parse_with(Backend::Nom, "Game 1: 3 blue\nGame 2: 4 purple\n")
+    else {
+        panic!("purple cubes should not parse");
+    };
+    assert_eq!(hint, Some("colors are `red`, `green` or `blue`"));
+    assert_eq!(
+        (span.line, span.column, span.text.as_str()),
+        (2, 11, "purple")
+    );
+}
+
+#[test]
+fn streams() {
+    let example = include_str!("../../input/day02/example01.txt");
+    for part in Part::BOTH {
+        let games = Day02::parse(example).unwrap();
+        let solved = match part {
+            Part::One => Day02::part1(&games),
+            Part::Two => Day02::part2(&games),
+        };
+        let streamed = Day02::stream(example.as_bytes(), Policy::Strict, part);
+        assert_eq!(streamed.ok(), solved.ok());
+    }
+    let input = "Game 1: 9999999999 red, 9999999999 green, 1 blue\nGame 2: 4 purple\n";
+    let Err(StreamError::Solve(error)) = Day02::stream(input.as_bytes(), Policy::Strict, Part::Two)
+    else {
+        panic!("purple cubes should not parse");
+    };
+    assert_eq!(error.line(), Some(2));
+}
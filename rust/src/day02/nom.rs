@@ -0,0 +1,87 @@
+//! A nom parser for games, accepting the same lines as `day02.pest`.
+
+use ::nom::branch::alt;
+use ::nom::bytes::complete::tag;
+use ::nom::character::complete::digit1;
+use ::nom::combinator::{cut, eof, map, map_res, value};
+use ::nom::error::{context, VerboseError};
+use ::nom::multi::separated_list1;
+use ::nom::sequence::{preceded, separated_pair, terminated};
+use ::nom::IResult;
+
+use super::{hint, Cubes, Day02, GameRecord, Reveal, Rule};
+use crate::error::Error;
+use crate::solution::Solution;
+
+type Parsed<'a, T> = IResult<&'a str, T, VerboseError<&'a str>>;
+
+/// The grammar rule a context is named after.
+fn rule(context: &str) -> Option<Rule> {
+    match context {
+        "game" => Some(Rule::game),
+        "id" => Some(Rule::id),
+        "count" => Some(Rule::count),
+        "color" => Some(Rule::color),
+        "show" => Some(Rule::show),
+        "reveal" => Some(Rule::reveal),
+        "EOI" => Some(Rule::EOI),
+        _ => None,
+    }
+}
+
+fn number(input: &str) -> Parsed<'_, u64> {
+    map_res(digit1, str::parse)(input)
+}
+
+fn color(input: &str) -> Parsed<'_, fn(u64) -> Cubes> {
+    context(
+        "color",
+        alt((
+            value(Cubes::Red as fn(u64) -> Cubes, tag("red")),
+            value(Cubes::Blue as fn(u64) -> Cubes, tag("blue")),
+            value(Cubes::Green as fn(u64) -> Cubes, tag("green")),
+        )),
+    )(input)
+}
+
+/// `3 blue`
+fn show(input: &str) -> Parsed<'_, Cubes> {
+    context(
+        "show",
+        map(
+            separated_pair(context("count", number), tag(" "), color),
+            |(count, color)| color(count),
+        ),
+    )(input)
+}
+
+/// `3 blue, 4 red`, where a separator must be followed by a show.
+fn reveal(input: &str) -> Parsed<'_, Reveal> {
+    context("reveal", map(separated_list1(tag(", "), cut(show)), Reveal))(input)
+}
+
+/// `Game 23: 3 blue, 4 red; 1 red`
+fn record(input: &str) -> Parsed<'_, GameRecord> {
+    context(
+        "game",
+        map(
+            terminated(
+                separated_pair(
+                    preceded(tag("Game "), context("id", number)),
+                    tag(": "),
+                    separated_list1(tag("; "), cut(reveal)),
+                ),
+                context("EOI", eof),
+            ),
+            |(id, reveals)| GameRecord { id, reveals },
+        ),
+    )(input)
+}
+
+/// Parses a game, with errors pointing into `line` like those of the pest
+/// parser.
+pub fn game(line: &str) -> Result<GameRecord, Error> {
+    record(line)
+        .map(|(_, game)| game)
+        .map_err(|e| Error::nom(Day02::DAY, line, e, |c| rule(c).and_then(hint)))
+}
@@ -0,0 +1,365 @@
+use std::collections::BTreeSet;
+use std::fmt;
+use std::sync::OnceLock;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Span};
+#[cfg(feature = "fast")]
+use crate::solution::Part;
+use crate::solution::Solution;
+use crate::validate::Report;
+
+#[cfg(feature = "fast")]
+pub mod bytes;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub struct Coord(pub i64, pub i64);
+
+// orthogonal and diagonal neighbor vectors
+pub const NEIGHBORS: [Coord; 8] = [
+    Coord(-1, -1),
+    Coord(0, -1),
+    Coord(1, -1),
+    Coord(-1, 0),
+    Coord(1, 0),
+    Coord(-1, 1),
+    Coord(0, 1),
+    Coord(1, 1),
+];
+
+impl Coord {
+    pub fn add(&self, addend: &Coord) -> Self {
+        let Coord(x1, y1) = self;
+        let Coord(x2, y2) = addend;
+        Coord(x1 + x2, y1 + y2)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cell {
+    Number(char),
+    /// Any character other than a digit or `.`, kept for formatting.
+    Symbol(char),
+    Empty,
+}
+
+impl From<char> for Cell {
+    fn from(value: char) -> Self {
+        match value {
+            '0' | '1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9' => Self::Number(value),
+            '.' => Self::Empty,
+            _ => Self::Symbol(value),
+        }
+    }
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Number(value) | Self::Symbol(value) => write!(f, "{value}"),
+            Self::Empty => write!(f, "."),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PartNumber {
+    pub value: u64,
+    pub symbols: Vec<Coord>,
+}
+
+/// Serialized as its rows of puzzle text, which are parsed again when
+/// deserializing.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "Vec<String>", into = "Vec<String>")]
+pub struct Grid(pub Vec<Vec<Cell>>);
+
+/// The grid as puzzle input, one line per row.
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in &self.0 {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Grid> for Vec<String> {
+    fn from(grid: Grid) -> Self {
+        grid.0
+            .iter()
+            .map(|row| row.iter().map(Cell::to_string).collect())
+            .collect()
+    }
+}
+
+impl TryFrom<Vec<String>> for Grid {
+    type Error = String;
+
+    /// Errors point at a row, without naming the day again.
+    fn try_from(rows: Vec<String>) -> Result<Self, Self::Error> {
+        Grid::try_from(rows.join("\n").as_str()).map_err(|e| match e {
+            Error::Syntax { span, message, .. } => {
+                format!("row {}, column {}: {message}", span.line, span.column)
+            }
+            e => e.to_string(),
+        })
+    }
+}
+
+impl TryFrom<&str> for Grid {
+    type Error = Error;
+
+    /// Rejects part numbers too large for a `u64`.
+    fn try_from(lines: &str) -> Result<Self, Self::Error> {
+        for (index, line) in lines.lines().enumerate() {
+            check_row(line).map_err(|e| e.on_line(index))?;
+        }
+        // a carriage return is a line ending, even without a line feed
+        let rows = lines
+            .lines()
+            .map(|line| parse_row(line.trim_end_matches('\r')))
+            .collect::<Vec<Vec<Cell>>>();
+        Ok(Self(rows))
+    }
+}
+
+impl Grid {
+    pub fn adjacent_symbols(&self, check: &[Coord]) -> Option<Vec<Coord>> {
+        let mut gears: Vec<Coord> = Vec::new();
+        // Bounds checking required.
+        for Coord(x, y) in adjacent_cells(check) {
+            if let Some(row) = self.0.get(y as usize) {
+                if let Some(Cell::Symbol(_)) = row.get(x as usize) {
+                    gears.push(Coord(x, y));
+                }
+            }
+        }
+        if gears.is_empty() {
+            None
+        } else {
+            Some(gears)
+        }
+    }
+
+    /// Panics on numbers too large for a `u64`, which parsing rejects.
+    pub fn part_numbers(&self) -> Vec<PartNumber> {
+        let mut part_numbers: Vec<PartNumber> = Vec::new();
+        for (y, row) in self.0.iter().enumerate() {
+            let mut potential_part_number: Vec<char> = Vec::new();
+            let mut potential_part_number_coords: Vec<Coord> = Vec::new();
+            for (x, cell) in row.iter().enumerate() {
+                match cell {
+                    Cell::Number(value) => {
+                        potential_part_number.push(*value);
+                        potential_part_number_coords.push(Coord(x as i64, y as i64));
+                    }
+                    Cell::Empty | Cell::Symbol(_) if !potential_part_number.is_empty() => {
+                        if let Some(symbols) = self.adjacent_symbols(&potential_part_number_coords)
+                        {
+                            part_numbers.push(PartNumber {
+                                value: potential_part_number
+                                    .iter()
+                                    .collect::<String>()
+                                    .parse::<u64>()
+                                    .expect("a part number fits in a u64"),
+                                symbols,
+                            });
+                        }
+                        potential_part_number.clear();
+                        potential_part_number_coords.clear();
+                    }
+                    _ => continue,
+                }
+            }
+            if !potential_part_number.is_empty() {
+                if let Some(symbols) = self.adjacent_symbols(&potential_part_number_coords) {
+                    part_numbers.push(PartNumber {
+                        value: potential_part_number
+                            .iter()
+                            .collect::<String>()
+                            .parse::<u64>()
+                            .expect("a part number fits in a u64"),
+                        symbols,
+                    });
+                }
+            }
+            potential_part_number.clear();
+            potential_part_number_coords.clear();
+        }
+        part_numbers
+    }
+}
+
+/// Rejects part numbers of a row too large for a `u64`, pointing at line 1.
+fn check_row(line: &str) -> Result<(), Error> {
+    static NUMBERS: OnceLock<Regex> = OnceLock::new();
+    let numbers = NUMBERS.get_or_init(|| Regex::new("[0-9]+").unwrap());
+    for number in numbers.find_iter(line) {
+        if let Err(e) = number.as_str().parse::<u64>() {
+            let span = Span {
+                line: 1,
+                column: line[..number.start()].chars().count() + 1,
+                text: number.as_str().to_string(),
+                source: line.to_string(),
+            };
+            return Err(Error::syntax(Day03::DAY, span, e.to_string()));
+        }
+    }
+    Ok(())
+}
+
+pub fn parse_row<S: AsRef<str>>(line: S) -> Vec<Cell> {
+    line.as_ref().chars().map(Into::<Cell>::into).collect()
+}
+
+/// May return invalid bounds.
+pub fn adjacent_cells(coords: &[Coord]) -> BTreeSet<Coord> {
+    let mut adjacent = BTreeSet::new();
+    for neighbor in NEIGHBORS {
+        for origin in coords {
+            adjacent.insert(origin.add(&neighbor));
+        }
+    }
+    adjacent
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Grid;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Grid::try_from(input)
+    }
+
+    /// Every row is checked on its own, so all rows with numbers too large
+    /// are reported.
+    fn validate(input: &str) -> Report {
+        Report::lines("rows", input, check_row)
+    }
+
+    fn part1(grid: &Grid) -> Result<u64, Error> {
+        grid.part_numbers()
+            .iter()
+            .try_fold(0u64, |sum, part_number| sum.checked_add(part_number.value))
+            .ok_or(Error::overflow(Self::DAY))
+    }
+
+    fn part2(grid: &Grid) -> Result<u64, Error> {
+        gear_ratios(grid).ok_or(Error::overflow(Self::DAY))
+    }
+
+    #[cfg(feature = "fast")]
+    fn solve_bytes(input: &[u8], part: Part) -> Result<u64, Error> {
+        self::bytes::solve(input, part)
+    }
+}
+
+/// `None` if the sum overflows.
+fn gear_ratios(grid: &Grid) -> Option<u64> {
+    let part_numbers: Vec<PartNumber> = grid.part_numbers();
+    let potential_gears: BTreeSet<Coord> = part_numbers
+        .iter()
+        .cloned()
+        .flat_map(|part_number| part_number.symbols)
+        .collect();
+    let mut gear_ratios: Vec<u64> = Vec::new();
+    for gear in potential_gears {
+        let matching_part_numbers: Vec<&PartNumber> = part_numbers
+            .iter()
+            .filter(|part_number| part_number.symbols.contains(&gear))
+            .collect();
+        if let &[one, two] = matching_part_numbers.as_slice() {
+            gear_ratios.push(one.value.checked_mul(two.value)?);
+        }
+    }
+    gear_ratios
+        .iter()
+        .try_fold(0u64, |sum, ratio| sum.checked_add(*ratio))
+}
+
+#[test]
+fn row() {
+    let row = "467..114..";
+    use Cell::{Empty, Number};
+    assert_eq!(
+        parse_row(row),
+        vec![
+            Number('4'),
+            Number('6'),
+            Number('7'),
+            Empty,
+            Empty,
+            Number('1'),
+            Number('1'),
+            Number('4'),
+            Empty,
+            Empty
+        ]
+    );
+}
+
+#[test]
+fn round_trip() {
+    let example = include_str!("../../input/day03/example01.txt");
+    let grid = Day03::parse(example).unwrap();
+    assert_eq!(grid.to_string(), example);
+    assert_eq!(Day03::parse(&grid.to_string()).unwrap(), grid);
+}
+
+#[test]
+fn example01() {
+    let example = include_str!("../../input/day03/example01.txt");
+    let grid = Day03::parse(example).unwrap();
+    assert_eq!(Day03::part1(&grid), Ok(4361));
+}
+
+#[test]
+fn example02() {
+    let example = include_str!("../../input/day03/example01.txt");
+    let grid = Day03::parse(example).unwrap();
+    assert_eq!(Day03::part2(&grid), Ok(467835));
+}
+
+#[test]
+fn large_numbers() {
+    let Err(error) = Day03::parse("12*.\n.99999999999999999999999\n") else {
+        panic!("the part number should not fit");
+    };
+    assert_eq!(
+        error.to_string(),
+        "day 03, line 2, column 2, at \"99999999999999999999999\": number too large to fit in target type"
+    );
+    let grid = Day03::parse("9999999999*9999999999\n").unwrap();
+    assert_eq!(Day03::part2(&grid), Err(Error::overflow(3)));
+}
+
+#[test]
+fn validate_all_rows() {
+    let input = "1.99999999999999999999999\n2*3\n99999999999999999999999.4\n";
+    let report = Day03::validate(input);
+    assert_eq!(report.to_string(), "1 of 3 rows accepted, 2 rejected");
+    let lines: Vec<Option<usize>> = report.errors.iter().map(Error::line).collect();
+    assert_eq!(lines, vec![Some(1), Some(3)]);
+}
+
+#[test]
+fn json() {
+    let grid = Day03::parse("467..\n...*.\n").unwrap();
+    let json = serde_json::to_string(&grid).unwrap();
+    assert_eq!(json, r#"["467..","...*."]"#);
+    assert_eq!(serde_json::from_str::<Grid>(&json).unwrap(), grid);
+    let error = serde_json::from_str::<Grid>(r#"["1", "99999999999999999999999"]"#).unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("row 2, column 1: number too large"));
+}
@@ -0,0 +1,182 @@
+//! The engine schematic as the bytes of the input, solved without building
+//! a [`Grid`](super::Grid) of cells.
+
+use super::Day03;
+use crate::bytes::{digits, lines, number, starts_char};
+use crate::error::{Error, Span};
+use crate::solution::{Part, Solution};
+
+/// Anything but a digit or `.`, by the first byte of its character.
+fn is_symbol(byte: u8) -> bool {
+    !byte.is_ascii_digit() && byte != b'.'
+}
+
+/// A row of the schematic, with a column per character.
+#[derive(Debug, Clone, Copy)]
+struct Row<'a> {
+    bytes: &'a [u8],
+    /// Whether every byte is a column of its own.
+    ascii: bool,
+}
+
+impl<'a> Row<'a> {
+    /// The first byte of the character at `column`.
+    fn get(&self, column: i64) -> Option<u8> {
+        let column = usize::try_from(column).ok()?;
+        if self.ascii {
+            self.bytes.get(column).copied()
+        } else {
+            self.chars().nth(column)
+        }
+    }
+
+    /// The first byte of every character.
+    fn chars(&self) -> impl Iterator<Item = u8> + 'a {
+        self.bytes.iter().copied().filter(|&byte| starts_char(byte))
+    }
+
+    /// The columns of the symbols.
+    fn symbols(&self) -> impl Iterator<Item = i64> + 'a {
+        self.chars()
+            .enumerate()
+            .filter(|&(_, byte)| is_symbol(byte))
+            .map(|(column, _)| column as i64)
+    }
+
+    /// Every number, with its first and last column.
+    fn numbers(&self) -> impl Iterator<Item = (u64, i64, i64)> + 'a {
+        let mut chars = self.chars().enumerate().peekable();
+        std::iter::from_fn(move || {
+            let (start, first) = chars.find(|(_, byte)| byte.is_ascii_digit())?;
+            let (mut value, mut end) = (u64::from(first - b'0'), start);
+            while let Some((column, digit)) = chars.next_if(|(_, byte)| byte.is_ascii_digit()) {
+                // parsing rejects numbers that overflow
+                value = value * 10 + u64::from(digit - b'0');
+                end = column;
+            }
+            Some((value, start as i64, end as i64))
+        })
+    }
+}
+
+/// The rows of the input, which are the only allocation.
+#[derive(Debug, Clone)]
+pub struct Schematic<'a> {
+    rows: Vec<Row<'a>>,
+}
+
+impl<'a> Schematic<'a> {
+    /// Rejects part numbers too large for a `u64`, like parsing a
+    /// [`Grid`](super::Grid).
+    pub fn new(input: &'a [u8]) -> Result<Self, Error> {
+        let mut rows = Vec::new();
+        for (index, line) in lines(input).enumerate() {
+            let mut offset = 0;
+            while let Some(start) = line[offset..].iter().position(u8::is_ascii_digit) {
+                let start = offset + start;
+                let end = start + digits(&line[start..]);
+                if number(&line[start..end]).is_none() {
+                    let span = Span {
+                        line: index + 1,
+                        column: line[..start].iter().filter(|&&b| starts_char(b)).count() + 1,
+                        text: String::from_utf8_lossy(&line[start..end]).into_owned(),
+                        source: String::from_utf8_lossy(line).into_owned(),
+                    };
+                    let message = "number too large to fit in target type";
+                    return Err(Error::syntax(Day03::DAY, span, message));
+                }
+                offset = end;
+            }
+            // a carriage return is a line ending, even without a line feed
+            let mut bytes = line;
+            while let Some(rest) = bytes.strip_suffix(b"\r") {
+                bytes = rest;
+            }
+            rows.push(Row {
+                bytes,
+                ascii: bytes.is_ascii(),
+            });
+        }
+        Ok(Schematic { rows })
+    }
+
+    fn row(&self, y: i64) -> Option<&Row<'a>> {
+        self.rows.get(usize::try_from(y).ok()?)
+    }
+
+    fn symbol_at(&self, x: i64, y: i64) -> bool {
+        self.row(y)
+            .and_then(|row| row.get(x))
+            .is_some_and(is_symbol)
+    }
+
+    /// The numbers next to a symbol, also diagonally.
+    pub fn part_numbers(&self) -> impl Iterator<Item = u64> + '_ {
+        self.rows.iter().zip(0..).flat_map(move |(row, y)| {
+            row.numbers()
+                .filter(move |&(_, start, end)| {
+                    (start - 1..=end + 1).any(|x| (y - 1..=y + 1).any(|y| self.symbol_at(x, y)))
+                })
+                .map(|(value, _, _)| value)
+        })
+    }
+
+    /// The products of the two part numbers of every symbol next to exactly
+    /// two, `None` if one overflows.
+    pub fn gear_ratios(&self) -> impl Iterator<Item = Option<u64>> + '_ {
+        self.rows.iter().zip(0..).flat_map(move |(row, y)| {
+            row.symbols().filter_map(move |x| {
+                let mut count = 0;
+                let mut ratio = Some(1u64);
+                for row in (y - 1..=y + 1).filter_map(|y| self.row(y)) {
+                    for (value, _, _) in row
+                        .numbers()
+                        .filter(|&(_, start, end)| start - 1 <= x && x <= end + 1)
+                    {
+                        count += 1;
+                        ratio = ratio.and_then(|ratio| ratio.checked_mul(value));
+                    }
+                }
+                (count == 2).then_some(ratio)
+            })
+        })
+    }
+}
+
+/// Solves a part of the input, with no allocation but the rows.
+pub fn solve(input: &[u8], part: Part) -> Result<u64, Error> {
+    let schematic = Schematic::new(input)?;
+    let total = match part {
+        Part::One => schematic
+            .part_numbers()
+            .try_fold(0u64, |sum, value| sum.checked_add(value)),
+        Part::Two => schematic
+            .gear_ratios()
+            .try_fold(0u64, |sum, ratio| sum.checked_add(ratio?)),
+    };
+    total.ok_or(Error::overflow(Day03::DAY))
+}
+
+#[test]
+fn matches_grid() {
+    let example = include_str!("../../../input/day03/example01.txt");
+    let inputs = [
+        example,
+        "467..\n...*.\n..35.\n",
+        "1*1\r\n.\r\r\n2$2\n",
+        "5é*é5\n.7..\n",
+        "12*.\n.99999999999999999999999\n",
+        "9999999999*9999999999\n",
+        "1\n*\n",
+        "",
+    ];
+    for input in inputs {
+        for part in Part::BOTH {
+            let solved = Day03::parse(input).and_then(|grid| match part {
+                Part::One => Day03::part1(&grid),
+                Part::Two => Day03::part2(&grid),
+            });
+            assert_eq!(solve(input.as_bytes(), part), solved, "{input}");
+        }
+    }
+}
@@ -0,0 +1,317 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::BufRead;
+
+use pest::iterators::Pair;
+use pest::Parser;
+use pest_derive::Parser;
+use serde::{Deserialize, Serialize};
+
+use crate::backend::Backend;
+use crate::error::{Error, Span};
+use crate::policy::Policy;
+use crate::solution::{Part, Solution};
+use crate::stream::{self, StreamError};
+use crate::validate::Report;
+
+#[cfg(feature = "fast")]
+pub mod bytes;
+mod nom;
+
+#[derive(Parser)]
+#[grammar = "day04.pest"]
+struct CardsParser;
+
+fn hint(rule: Rule) -> Option<&'static str> {
+    match rule {
+        Rule::card => Some("cards start with their id, like `Card 1:`"),
+        Rule::number | Rule::winning | Rule::yours => {
+            Some("numbers are separated by spaces, and the winning numbers from yours by ` | `")
+        }
+        Rule::EOI => Some("a card ends with your numbers"),
+        _ => None,
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Card {
+    pub id: u64,
+    pub winning: Vec<u64>,
+    pub yours: Vec<u64>,
+}
+
+/// A card as a line of puzzle input (without the line break), with numbers
+/// separated by single spaces.
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Card {}:", self.id)?;
+        for number in &self.winning {
+            write!(f, " {number}")?;
+        }
+        write!(f, " |")?;
+        for number in &self.yours {
+            write!(f, " {number}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Cards(pub Vec<Card>);
+
+impl fmt::Display for Cards {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for card in &self.0 {
+            writeln!(f, "{card}")?;
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<&str> for Card {
+    type Error = Error;
+
+    /// Errors point into `line`, as the first line of the input.
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let day = Day04::DAY;
+        let number = |pair: Pair<Rule>| {
+            pair.as_str()
+                .trim()
+                .parse::<u64>()
+                .map_err(|e| Error::syntax(day, Span::pair(&pair), e.to_string()))
+        };
+        let mut card = CardsParser::parse(Rule::input, line)
+            .map_err(|e| Error::pest(day, &e, hint))?
+            .next()
+            .expect("a parsed card")
+            .into_inner();
+        let header = card.next().expect("a card has a header");
+        let id = number(header.into_inner().next().expect("a card has an id"))?;
+        let winning_numbers = card.next().expect("a card has winning numbers");
+        let your_numbers = card.next().expect("a card has your numbers");
+        let winning = winning_numbers
+            .into_inner()
+            .map(number)
+            .collect::<Result<Vec<u64>, Error>>()?;
+        let yours = your_numbers
+            .into_inner()
+            .map(number)
+            .collect::<Result<Vec<u64>, Error>>()?;
+        Ok(Card { id, winning, yours })
+    }
+}
+
+impl Card {
+    pub fn matches(&self) -> u64 {
+        self.yours
+            .iter()
+            .filter(|yours| self.winning.contains(yours))
+            .count() as u64
+    }
+
+    /// `None` if the points overflow.
+    pub fn points(&self) -> Option<u64> {
+        points(self.matches())
+    }
+}
+
+/// The points of a card with `matches` matching numbers, `None` if they
+/// overflow.
+fn points(matches: u64) -> Option<u64> {
+    match matches {
+        0 => Some(0),
+        matches => 1u64.checked_shl(u32::try_from(matches - 1).ok()?),
+    }
+}
+
+impl TryFrom<&str> for Cards {
+    type Error = Error;
+
+    fn try_from(lines: &str) -> Result<Self, Self::Error> {
+        Ok(Cards(
+            lines
+                .lines()
+                .enumerate()
+                .map(|(index, line)| Card::try_from(line).map_err(|e| e.on_line(index)))
+                .collect::<Result<Vec<Card>, Error>>()?,
+        ))
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Cards;
+    type Answer = u64;
+
+    const BACKENDS: &'static [Backend] = &[Backend::Pest, Backend::Nom];
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Cards::try_from(input)
+    }
+
+    fn parse_with(backend: Backend, input: &str) -> Result<Self::Input, Error> {
+        match backend {
+            Backend::Pest => Self::parse(input),
+            Backend::Nom => input
+                .lines()
+                .enumerate()
+                .map(|(index, line)| self::nom::card(line).map_err(|e| e.on_line(index)))
+                .collect::<Result<Vec<Card>, Error>>()
+                .map(Cards),
+        }
+    }
+
+    fn validate(input: &str) -> Report {
+        Report::lines("cards", input, |line| Card::try_from(line))
+    }
+
+    fn part1(cards: &Cards) -> Result<u64, Error> {
+        cards
+            .0
+            .iter()
+            .try_fold(0u64, |sum, card| sum.checked_add(card.points()?))
+            .ok_or(Error::overflow(Self::DAY))
+    }
+
+    fn part2(cards: &Cards) -> Result<u64, Error> {
+        total_copies(cards).ok_or(Error::overflow(Self::DAY))
+    }
+
+    /// Part 2 only keeps the copies won for the cards that follow.
+    fn stream(reader: impl BufRead, policy: Policy, part: Part) -> Result<u64, StreamError> {
+        // `None` once the sum overflows, which is reported after every card
+        // has parsed
+        let mut sum = Some(0u64);
+        // the copies won by earlier cards for the cards that follow, `None`
+        // once they overflow
+        let mut won: VecDeque<Option<u64>> = VecDeque::new();
+        stream::lines(reader, Self::DAY, policy, Self::tidy, |index, line| {
+            let card = Card::try_from(line).map_err(|e| e.on_line(index))?;
+            let value = match part {
+                Part::One => card.points(),
+                Part::Two => {
+                    let copies = won
+                        .pop_front()
+                        .unwrap_or(Some(0))
+                        .and_then(|won| won.checked_add(1));
+                    let matches = card.matches() as usize;
+                    if won.len() < matches {
+                        won.resize(matches, Some(0));
+                    }
+                    for won in won.iter_mut().take(matches) {
+                        *won = won
+                            .zip(copies)
+                            .and_then(|(won, copies)| won.checked_add(copies));
+                    }
+                    copies
+                }
+            };
+            sum = sum
+                .zip(value)
+                .and_then(|(sum, value)| sum.checked_add(value));
+            Ok(())
+        })?;
+        Ok(sum.ok_or(Error::overflow(Self::DAY))?)
+    }
+
+    #[cfg(feature = "fast")]
+    fn solve_bytes(input: &[u8], part: Part) -> Result<u64, Error> {
+        self::bytes::solve(input, part)
+    }
+}
+
+/// Cards win copies of the cards that follow them in the list. `None` if
+/// there are too many copies to count.
+fn total_copies(cards: &Cards) -> Option<u64> {
+    // start with one copy of every card
+    let mut copies: Vec<u64> = vec![1; cards.0.len()];
+    for (index, card) in cards.0.iter().enumerate() {
+        let current_copies = copies[index];
+        // increase all subsequent cards by number of copies of current card
+        for offset in 1..=card.matches() as usize {
+            if let Some(copies) = copies.get_mut(index + offset) {
+                *copies = copies.checked_add(current_copies)?;
+            }
+        }
+    }
+    copies
+        .iter()
+        .try_fold(0u64, |sum, copies| sum.checked_add(*copies))
+}
+
+#[test]
+fn example01() {
+    let example = include_str!("../../input/day04/example01.txt");
+    let cards = Day04::parse(example).unwrap();
+    assert_eq!(Day04::part1(&cards), Ok(13))
+}
+
+#[test]
+fn example02() {
+    let example = include_str!("../../input/day04/example01.txt");
+    let cards = Day04::parse(example).unwrap();
+    assert_eq!(Day04::part2(&cards), Ok(30));
+}
+
+#[test]
+fn round_trip() {
+    let example = include_str!("../../input/day04/example01.txt");
+    let cards = Day04::parse(example).unwrap();
+    let formatted = cards.to_string();
+    assert!(formatted.starts_with("Card 1: 41 48 83 86 17 | 83 86 6 31 17 9 48 53\n"));
+    assert_eq!(Day04::parse(&formatted).unwrap(), cards);
+}
+
+#[test]
+fn overflow() {
+    let numbers: Vec<String> = (0..65).map(|number| number.to_string()).collect();
+    let numbers = numbers.join(" ");
+    let card = format!("Card 1: {numbers} | {numbers}\n");
+    let cards = Day04::parse(&card).unwrap();
+    assert_eq!(Day04::part1(&cards), Err(Error::overflow(4)));
+}
+
+#[test]
+fn nom_matches_pest() {
+    let lines = [
+        "Card 1:41 48 |  83",
+        "Card   12: 41 48 | 83 86",
+        "Card 1: 41 48  | 83",
+        "Card 1: 41 48 |83",
+        "Card 1: 41 48 | 83 ",
+        "Card 1: | 83",
+        "Card 1 41 | 83",
+        "Card1: 41 | 83",
+        "Card 1: 41 | 99999999999999999999",
+        "",
+    ];
+    let example = include_str!("../../input/day04/example01.txt");
+    for line in example.lines().chain(lines) {
+        crate::backend::assert_agree::<Day04>(line);
+    }
+}
+
+#[test]
+fn streams() {
+    let example = include_str!("../../input/day04/example01.txt");
+    let numbers: Vec<String> = (0..65).map(|number| number.to_string()).collect();
+    let numbers = numbers.join(" ");
+    let overflow = format!("Card 1: {numbers} | {numbers}\n");
+    for input in [example, &overflow, "Card 1: 1 2 | 1 2\nCard 2: 3 | 3\n"] {
+        for part in Part::BOTH {
+            let cards = Day04::parse(input).unwrap();
+            let solved = match part {
+                Part::One => Day04::part1(&cards),
+                Part::Two => Day04::part2(&cards),
+            };
+            let streamed = match Day04::stream(input.as_bytes(), Policy::Strict, part) {
+                Err(StreamError::Solve(error)) => Err(error),
+                streamed => Ok(streamed.unwrap()),
+            };
+            assert_eq!(streamed, solved, "{input}");
+        }
+    }
+}
@@ -0,0 +1,235 @@
+//! A parser for cards over the bytes of the input, accepting the same lines
+//! as `day04.pest` without allocating.
+
+use super::{hint, points, Card, Day04, Rule};
+use crate::bytes::{digits, lines, number};
+use crate::error::{Error, Span};
+use crate::solution::{Part, Solution};
+
+/// A card borrowing its numbers from a line of the input.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CardBytes<'a> {
+    pub id: u64,
+    winning: &'a [u8],
+    yours: &'a [u8],
+}
+
+impl<'a> CardBytes<'a> {
+    pub fn winning(&self) -> Numbers<'a> {
+        Numbers(self.winning)
+    }
+
+    pub fn yours(&self) -> Numbers<'a> {
+        Numbers(self.yours)
+    }
+
+    /// Like [`Card::matches`].
+    pub fn matches(&self) -> u64 {
+        // the winning numbers below 128, and whether there are others
+        let (mut small, mut large) = (0u128, false);
+        for winning in self.winning() {
+            match 1u128.checked_shl(winning.try_into().unwrap_or(u32::MAX)) {
+                Some(bit) => small |= bit,
+                None => large = true,
+            }
+        }
+        self.yours()
+            .filter(
+                |&yours| match 1u128.checked_shl(yours.try_into().unwrap_or(u32::MAX)) {
+                    Some(bit) => small & bit != 0,
+                    None => large && self.winning().any(|winning| winning == yours),
+                },
+            )
+            .count() as u64
+    }
+}
+
+impl From<CardBytes<'_>> for Card {
+    fn from(card: CardBytes) -> Self {
+        Card {
+            id: card.id,
+            winning: card.winning().collect(),
+            yours: card.yours().collect(),
+        }
+    }
+}
+
+/// Numbers separated by spaces, which parsing has checked.
+#[derive(Debug, Clone)]
+pub struct Numbers<'a>(&'a [u8]);
+
+impl Iterator for Numbers<'_> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let start = self.0.iter().position(u8::is_ascii_digit)?;
+        let rest = &self.0[start..];
+        let end = digits(rest);
+        self.0 = &rest[end..];
+        number(&rest[..end])
+    }
+}
+
+/// An error at `offset` of `line` where `rule` was expected, like those of
+/// the pest parser.
+fn expected(line: &[u8], offset: usize, rule: Rule) -> Error {
+    Error::Syntax {
+        day: Day04::DAY,
+        span: Box::new(Span::at_bytes(line, offset)),
+        message: format!("expected {rule:?}"),
+        expected: vec![format!("{rule:?}")],
+        hint: hint(rule),
+    }
+}
+
+/// The grammar's `number` at `offset`: its value and the offset after it,
+/// or `None` if there are no digits after the spaces.
+fn number_at(line: &[u8], offset: usize) -> Result<Option<(u64, usize)>, Error> {
+    let start = offset + line[offset..].iter().take_while(|&&b| b == b' ').count();
+    let end = start + digits(&line[start..]);
+    if end == start {
+        return Ok(None);
+    }
+    match number(&line[start..end]) {
+        Some(value) => Ok(Some((value, end))),
+        None => Err(Error::syntax(
+            Day04::DAY,
+            Span::at_bytes(line, start),
+            "number too large to fit in target type",
+        )),
+    }
+}
+
+/// The offset after `number+` at `offset`, which matches `rule`.
+fn numbers_at(line: &[u8], offset: usize, rule: Rule) -> Result<usize, Error> {
+    let mut end = offset;
+    while let Some((_, after)) = number_at(line, end)? {
+        end = after;
+    }
+    if end == offset {
+        return Err(expected(line, offset, rule));
+    }
+    Ok(end)
+}
+
+/// Parses a card, with errors pointing into `line` as the first line of the
+/// input.
+pub fn card(line: &[u8]) -> Result<CardBytes<'_>, Error> {
+    let spaces = match line.strip_prefix(b"Card") {
+        Some(rest) => rest.iter().take_while(|&&b| b == b' ').count(),
+        None => 0,
+    };
+    if spaces == 0 {
+        return Err(expected(line, 0, Rule::card));
+    }
+    let Some((id, offset)) = number_at(line, 4 + spaces)? else {
+        return Err(expected(line, 4 + spaces, Rule::number));
+    };
+    if line.get(offset) != Some(&b':') {
+        return Err(expected(line, offset, Rule::card));
+    }
+    let winning = offset + 1;
+    let separator = numbers_at(line, winning, Rule::winning)?;
+    if !line[separator..].starts_with(b" | ") {
+        return Err(expected(line, separator, Rule::yours));
+    }
+    let yours = separator + 3;
+    let end = numbers_at(line, yours, Rule::yours)?;
+    if end != line.len() {
+        return Err(expected(line, end, Rule::EOI));
+    }
+    Ok(CardBytes {
+        id,
+        winning: &line[winning..separator],
+        yours: &line[yours..end],
+    })
+}
+
+/// Every card of the input.
+pub fn cards(input: &[u8]) -> impl Iterator<Item = Result<CardBytes<'_>, Error>> {
+    lines(input)
+        .enumerate()
+        .map(|(index, line)| card(line).map_err(|e| e.on_line(index)))
+}
+
+/// Solves a part while parsing. Answers that overflow are only reported
+/// once the whole input has parsed, like when solving parsed [`Cards`]
+/// (part 2 allocates the copies of every card).
+///
+/// [`Cards`]: super::Cards
+pub fn solve(input: &[u8], part: Part) -> Result<u64, Error> {
+    let total = match part {
+        Part::One => {
+            let mut total = Some(0u64);
+            for card in cards(input) {
+                let points = points(card?.matches());
+                total = total
+                    .zip(points)
+                    .and_then(|(total, points)| total.checked_add(points));
+            }
+            total
+        }
+        Part::Two => {
+            // start with one copy of every card
+            let mut copies = vec![1u64; lines(input).count()];
+            let mut overflowed = false;
+            for (index, card) in cards(input).enumerate() {
+                let (won, current) = (card?.matches() as usize, copies[index]);
+                for copies in copies.iter_mut().skip(index + 1).take(won) {
+                    match copies.checked_add(current) {
+                        Some(sum) => *copies = sum,
+                        None => overflowed = true,
+                    }
+                }
+            }
+            let total = copies
+                .iter()
+                .try_fold(0u64, |sum, copies| sum.checked_add(*copies));
+            total.filter(|_| !overflowed)
+        }
+    };
+    total.ok_or(Error::overflow(Day04::DAY))
+}
+
+#[test]
+fn matches_pest() {
+    let lines = [
+        "Card 1:41 48 |  83",
+        "Card   12: 41 48 | 83 86",
+        "Card 1: 41 48  | 83",
+        "Card 1: 41 48 |83",
+        "Card 1: 41 48 | 83 ",
+        "Card 1: | 83",
+        "Card 1 41 | 83",
+        "Card1: 41 | 83",
+        "Card 1: 41 | 99999999999999999999",
+        "Card 99999999999999999999: 41 | 9",
+        "Card 1: 4\u{e9} | 9",
+        "",
+    ];
+    let example = include_str!("../../../input/day04/example01.txt");
+    for line in example.lines().chain(lines) {
+        match (card(line.as_bytes()), Card::try_from(line)) {
+            (Ok(bytes), Ok(pest)) => assert_eq!(Card::from(bytes), pest, "{line}"),
+            (Err(bytes), Err(pest)) => assert_eq!(bytes.line(), pest.line(), "{line}"),
+            (bytes, pest) => panic!("{line}: {bytes:?} vs {pest:?}"),
+        }
+    }
+    let numbers: Vec<String> = (0..65).map(|number| number.to_string()).collect();
+    let numbers = numbers.join(" ");
+    let overflow = format!("Card 1: {numbers} | {numbers}\n");
+    for input in [example, &overflow, "Card 1: 1 | 1\nCard 2: x\n", ""] {
+        for part in Part::BOTH {
+            let parsed = Day04::parse(input);
+            let solved = parsed.and_then(|cards| match part {
+                Part::One => Day04::part1(&cards),
+                Part::Two => Day04::part2(&cards),
+            });
+            assert_eq!(
+                solve(input.as_bytes(), part).map_err(|e| e.line()),
+                solved.map_err(|e| e.line()),
+                "{input}"
+            );
+        }
+    }
+}
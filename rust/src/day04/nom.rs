@@ -0,0 +1,72 @@
+//! A nom parser for cards, accepting the same lines as `day04.pest`.
+
+use ::nom::bytes::complete::{tag, take_while, take_while1};
+use ::nom::character::complete::digit1;
+use ::nom::combinator::{eof, map, map_res};
+use ::nom::error::{context, VerboseError};
+use ::nom::multi::many1;
+use ::nom::sequence::{delimited, preceded, terminated, tuple};
+use ::nom::IResult;
+
+use super::{hint, Card, Day04, Rule};
+use crate::error::Error;
+use crate::solution::Solution;
+
+type Parsed<'a, T> = IResult<&'a str, T, VerboseError<&'a str>>;
+
+/// The grammar rule a context is named after.
+fn rule(context: &str) -> Option<Rule> {
+    match context {
+        "card" => Some(Rule::card),
+        "winning" => Some(Rule::winning),
+        "yours" => Some(Rule::yours),
+        "number" => Some(Rule::number),
+        "EOI" => Some(Rule::EOI),
+        _ => None,
+    }
+}
+
+/// A number after any number of spaces, like ` 41`.
+fn number(input: &str) -> Parsed<'_, u64> {
+    context(
+        "number",
+        preceded(take_while(|c| c == ' '), map_res(digit1, str::parse)),
+    )(input)
+}
+
+/// `Card  1:`
+fn header(input: &str) -> Parsed<'_, u64> {
+    context(
+        "card",
+        delimited(
+            tuple((tag("Card"), take_while1(|c| c == ' '))),
+            number,
+            tag(":"),
+        ),
+    )(input)
+}
+
+/// `Card 1: 41 48 | 83 86`
+fn line(input: &str) -> Parsed<'_, Card> {
+    map(
+        tuple((
+            header,
+            terminated(
+                tuple((
+                    context("winning", many1(number)),
+                    context("yours", preceded(tag(" | "), many1(number))),
+                )),
+                context("EOI", eof),
+            ),
+        )),
+        |(id, (winning, yours))| Card { id, winning, yours },
+    )(input)
+}
+
+/// Parses a card, with errors pointing into `line` like those of the pest
+/// parser.
+pub fn card(line: &str) -> Result<Card, Error> {
+    self::line(line)
+        .map(|(_, card)| card)
+        .map_err(|e| Error::nom(Day04::DAY, line, e, |c| rule(c).and_then(hint)))
+}
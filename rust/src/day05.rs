@@ -0,0 +1,803 @@
+//! TODO: instead of operating on individual seeds, operate on ranges of seeds
+//! TODO: when given a range of seeds, first figure out which maps apply
+//! TODO: when you understand all maps that apply, fold over them (creating new ranges)
+//! TODO: can we collapse the maps? what does the one-pass map look like?
+
+use pest::iterators::Pair;
+use pest::Parser;
+use pest_derive::Parser;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::backend::Backend;
+use crate::error::{Error, Span};
+use crate::policy::Layout;
+use crate::solution::{Part, Solution};
+use crate::validate::Report;
+
+mod nom;
+
+#[derive(Parser)]
+#[grammar = "day05.pest"]
+struct InputParser;
+
+fn hint(rule: Rule) -> Option<&'static str> {
+    match rule {
+        Rule::seeds => Some("the first line lists the seeds, like `seeds: 79 14 55 13`"),
+        Rule::map | Rule::map_type | Rule::kind | Rule::from | Rule::to => {
+            Some("maps start with a header like `seed-to-soil map:`, followed by ranges")
+        }
+        Rule::range
+        | Rule::destination_start
+        | Rule::source_start
+        | Rule::range_length
+        | Rule::number => Some(
+            "each range is a line of three numbers: destination start, source start and length",
+        ),
+        _ => None,
+    }
+}
+
+// for part 2, still in progress
+#[allow(dead_code)]
+#[derive(Debug)]
+struct Seed(i64);
+
+/// Serialized as its three numbers, like a line of the puzzle input.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "[i64; 3]", into = "[i64; 3]")]
+pub struct Range {
+    pub destination_start: i64,
+    pub source_start: i64,
+    pub range_length: i64,
+}
+
+impl TryFrom<[i64; 3]> for Range {
+    type Error = String;
+
+    /// Rejects negative numbers, and ranges ending past `i64::MAX`.
+    fn try_from(numbers: [i64; 3]) -> Result<Self, Self::Error> {
+        let [destination_start, source_start, range_length] = numbers;
+        if numbers.iter().any(|number| *number < 0) {
+            return Err(format!("negative number in range {numbers:?}"));
+        }
+        if destination_start
+            .max(source_start)
+            .checked_add(range_length)
+            .is_none()
+        {
+            return Err("range is too long".to_string());
+        }
+        Ok(Range {
+            destination_start,
+            source_start,
+            range_length,
+        })
+    }
+}
+
+impl From<Range> for [i64; 3] {
+    fn from(range: Range) -> Self {
+        [
+            range.destination_start,
+            range.source_start,
+            range.range_length,
+        ]
+    }
+}
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Range {
+            destination_start,
+            source_start,
+            range_length,
+        } = self;
+        write!(f, "{destination_start} {source_start} {range_length}")
+    }
+}
+
+impl Range {
+    pub fn map(&self, value: i64) -> Option<i64> {
+        let start = self.source_start;
+        let end = self.source_start + (self.range_length) - 1;
+
+        if start <= value && value <= end {
+            let offset = value - self.source_start;
+            Some(self.destination_start + offset)
+        } else {
+            None
+        }
+    }
+
+    // Check a value range for overlap.
+    //
+    // Returns None for no overlap (meaning no modification to the range).
+    // Returns a new Vec<ValueRange> for overlap: the values before the range,
+    // the overlapping values with offset applied, and the values after it.
+    pub fn map_value_range(&self, value_range: &ValueRange, to: Kind) -> Option<Vec<ValueRange>> {
+        // ends are exclusive
+        let values_end = value_range.start + value_range.length;
+        let range_end = self.source_start + self.range_length;
+        let overlap_start = value_range.start.max(self.source_start);
+        let overlap_end = values_end.min(range_end);
+        if overlap_start >= overlap_end {
+            return None;
+        }
+        let mut ranges = Vec::new();
+        if value_range.start < overlap_start {
+            ranges.push(ValueRange {
+                start: value_range.start,
+                length: overlap_start - value_range.start,
+                kind: value_range.kind,
+            });
+        }
+        ranges.push(ValueRange {
+            start: self.destination_start + overlap_start - self.source_start,
+            length: overlap_end - overlap_start,
+            kind: to,
+        });
+        if overlap_end < values_end {
+            ranges.push(ValueRange {
+                start: overlap_end,
+                length: values_end - overlap_end,
+                kind: value_range.kind,
+            });
+        }
+        Some(ranges)
+    }
+}
+
+#[test]
+fn value_ranges() {
+    let range = Range {
+        destination_start: 100,
+        source_start: 10,
+        range_length: 10,
+    };
+    let value_range = ValueRange {
+        start: 5,
+        length: 10,
+        kind: Kind::Seed,
+    };
+    // seeds 5 to 9 stay, 10 to 14 become soils 100 to 104
+    assert_eq!(
+        range.map_value_range(&value_range, Kind::Soil).unwrap(),
+        vec![
+            ValueRange {
+                start: 5,
+                length: 5,
+                kind: Kind::Seed
+            },
+            ValueRange {
+                start: 100,
+                length: 5,
+                kind: Kind::Soil
+            }
+        ]
+    );
+    let seeds = |start, length| ValueRange {
+        start,
+        length,
+        kind: Kind::Seed,
+    };
+    let soils = |start, length| ValueRange {
+        start,
+        length,
+        kind: Kind::Soil,
+    };
+    for (values, mapped) in [
+        (seeds(12, 3), Some(vec![soils(102, 3)])),
+        (seeds(15, 10), Some(vec![soils(105, 5), seeds(20, 5)])),
+        (
+            seeds(0, 30),
+            Some(vec![seeds(0, 10), soils(100, 10), seeds(20, 10)]),
+        ),
+        (seeds(0, 10), None),
+        (seeds(20, 5), None),
+    ] {
+        assert_eq!(
+            range.map_value_range(&values, Kind::Soil),
+            mapped,
+            "{values:?}"
+        );
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Kind {
+    Seed,
+    Soil,
+    Fertilizer,
+    Water,
+    Light,
+    Temperature,
+    Humidity,
+    Location,
+}
+
+impl TryFrom<&str> for Kind {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        use Kind::*;
+
+        match value {
+            "seed" => Ok(Seed),
+            "soil" => Ok(Soil),
+            "fertilizer" => Ok(Fertilizer),
+            "water" => Ok(Water),
+            "light" => Ok(Light),
+            "temperature" => Ok(Temperature),
+            "humidity" => Ok(Humidity),
+            "location" => Ok(Location),
+            _ => Err(format!("invalid kind {value:?}")),
+        }
+    }
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Kind::*;
+
+        let kind = match self {
+            Seed => "seed",
+            Soil => "soil",
+            Fertilizer => "fertilizer",
+            Water => "water",
+            Light => "light",
+            Temperature => "temperature",
+            Humidity => "humidity",
+            Location => "location",
+        };
+        write!(f, "{kind}")
+    }
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct Value(pub i64, pub Kind);
+
+impl Value {
+    pub fn quantity(&self) -> i64 {
+        match self {
+            Value(quantity, _) => *quantity,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ValueRange {
+    pub start: i64,
+    pub length: i64,
+    pub kind: Kind,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Map {
+    pub from: Kind,
+    pub to: Kind,
+    pub ranges: Vec<Range>,
+}
+
+/// A map's header followed by one line per range.
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}-to-{} map:", self.from, self.to)?;
+        for range in &self.ranges {
+            writeln!(f, "{range}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Map {
+    /// Returns None is no values within the range are modified.
+    pub fn translate_range(&self, values: &[ValueRange]) -> Vec<ValueRange> {
+        let mut new_ranges: Vec<ValueRange> = Vec::new();
+        for value_range in values {
+            // fold over all ranges
+            let results =
+                self.ranges
+                    .iter()
+                    .fold(None, |new: Option<Vec<ValueRange>>, range: &Range| {
+                        if let Some(mut result) = range.map_value_range(value_range, self.to) {
+                            match new {
+                                None => Some(result),
+                                Some(mut older_results) => {
+                                    result.append(&mut older_results);
+                                    Some(result)
+                                }
+                            }
+                        } else {
+                            new
+                        }
+                    });
+            if let Some(mut new) = results {
+                // if any ranges applied, return the new value ranges
+                new_ranges.append(&mut new)
+            } else {
+                // otherwise, the value range is unmodified
+                new_ranges.push(ValueRange {
+                    start: value_range.start,
+                    length: value_range.length,
+                    kind: self.to,
+                });
+            }
+        }
+        new_ranges
+    }
+
+    pub fn translate(&self, value: &Value) -> Value {
+        let quantity: i64 = match value {
+            Value(quantity, kind) if *kind == self.from => *quantity,
+            _ => panic!("invalid mapping"),
+        };
+        let results = self
+            .ranges
+            .iter()
+            .filter_map(|range| range.map(quantity))
+            .collect::<Vec<i64>>();
+
+        match results.first() {
+            Some(result) => Value(*result, self.to),
+            None => Value(quantity, self.to),
+        }
+    }
+}
+
+/// Only calculate locations for each value once.
+#[allow(dead_code)]
+#[derive(Debug)]
+struct LocationCache(HashMap<Value, Value>);
+
+impl LocationCache {
+    #[allow(dead_code)]
+    fn new() -> Self {
+        LocationCache(HashMap::new())
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Almanac(pub Vec<Map>);
+
+/// The maps, separated by blank lines.
+impl fmt::Display for Almanac {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, map) in self.0.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{map}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Almanac {
+    /// Find a map with a particular source kind.
+    pub fn find_map(&self, kind: Kind) -> Option<&Map> {
+        self.0.iter().find(|map| map.from == kind)
+    }
+
+    /// Find the map for a kind, or explain that there is none.
+    fn map_from(&self, kind: Kind) -> Result<&Map, Error> {
+        self.find_map(kind)
+            .ok_or_else(|| Error::invalid(Day05::DAY, None, format!("no map from {kind:?}")))
+    }
+
+    /// Convert a value to a location value.
+    pub fn to_location(&self, value: &Value) -> Result<Value, Error> {
+        let mut value = value.clone();
+        // every map can be used at most once on the way to a location
+        for _ in 0..=self.0.len() {
+            if value.1 == Kind::Location {
+                return Ok(value);
+            }
+            value = self.map_from(value.1)?.translate(&value);
+        }
+        match value {
+            Value(_, Kind::Location) => Ok(value),
+            Value(_, kind) => Err(Error::invalid(
+                Day05::DAY,
+                None,
+                format!("maps from {kind:?} never reach a location"),
+            )),
+        }
+    }
+
+    /// Convert a value to a location value, using cached results.
+    #[allow(dead_code)]
+    fn cached_to_location(&self, cache: &mut LocationCache, value: &Value) -> Result<Value, Error> {
+        if let Some(result) = cache.0.get(value) {
+            Ok(result.clone())
+        } else {
+            let Value(_, kind) = value;
+            let mapped = self.map_from(*kind)?.translate(value);
+            match mapped {
+                Value(_, Kind::Location) => Ok(mapped),
+                _ => {
+                    let result = self.to_location(&mapped)?;
+                    cache.0.insert(value.clone(), result.clone());
+                    Ok(result)
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Input {
+    pub seeds: Vec<Value>,
+    pub almanac: Almanac,
+}
+
+/// The whole puzzle input, which parses back to the same seeds and maps.
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "seeds:")?;
+        for Value(quantity, _) in &self.seeds {
+            write!(f, " {quantity}")?;
+        }
+        write!(f, "\n\n{}", self.almanac)
+    }
+}
+
+impl Input {
+    fn solve1(&self) -> Result<i64, Error> {
+        let mut lowest = None;
+        for seed in &self.seeds {
+            let Value(quantity, _) = self.almanac.to_location(seed)?;
+            lowest = Some(lowest.map_or(quantity, |lowest: i64| lowest.min(quantity)));
+        }
+        lowest.ok_or_else(|| Error::invalid(Day05::DAY, None, "no seeds"))
+    }
+
+    /// [`Error::Unimplemented`] until part 2 has been solved.
+    #[allow(dead_code)]
+    fn part2_seeds(&self) -> Result<Vec<ValueRange>, Error> {
+        Err(Error::Unimplemented {
+            day: Day05::DAY,
+            part: Part::Two,
+        })
+    }
+
+    #[allow(dead_code)]
+    fn solve2(&self) -> Result<i64, Error> {
+        let _cache = LocationCache::new();
+        let _seeds = self.part2_seeds()?;
+        Err(Error::Unimplemented {
+            day: Day05::DAY,
+            part: Part::Two,
+        })
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const LAYOUT: Layout = Layout::Sections;
+
+    type Input = Input;
+    type Answer = i64;
+
+    const BACKENDS: &'static [Backend] = &[Backend::Pest, Backend::Nom];
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Input::try_from(input)
+    }
+
+    fn parse_with(backend: Backend, input: &str) -> Result<Self::Input, Error> {
+        match backend {
+            Backend::Pest => Self::parse(input),
+            Backend::Nom => self::nom::input(input),
+        }
+    }
+
+    /// The seeds and every map are validated as separate sections.
+    fn validate(input: &str) -> Report {
+        let mut report = Report::new("sections");
+        for (position, (index, section)) in sections(input).into_iter().enumerate() {
+            let result = match position {
+                0 => parse_section(Rule::seeds, section, parse_seeds).map(|_| ()),
+                _ => parse_section(Rule::map, section, parse_map).map(|_| ()),
+            };
+            report.add(result.map_err(|e| e.on_line(index)));
+        }
+        report
+    }
+
+    fn part1(input: &Input) -> Result<i64, Error> {
+        input.solve1()
+    }
+}
+
+fn number(pair: Pair<Rule>) -> Result<i64, Error> {
+    pair.as_str()
+        .trim()
+        .parse::<i64>()
+        .map_err(|e| Error::syntax(Day05::DAY, Span::pair(&pair), e.to_string()))
+}
+
+fn kind(pair: Pair<Rule>) -> Result<Kind, Error> {
+    Kind::try_from(pair.as_str()).map_err(|e| Error::syntax(Day05::DAY, Span::pair(&pair), e))
+}
+
+fn parse_seeds(seeds: Pair<Rule>) -> Result<Vec<Value>, Error> {
+    seeds
+        .into_inner()
+        .map(|pair| Ok(Value(number(pair)?, Kind::Seed)))
+        .collect()
+}
+
+fn parse_map(map: Pair<Rule>) -> Result<Map, Error> {
+    let mut map_tokens = map.into_inner();
+    let mut map_type = map_tokens.next().expect("a map has a type").into_inner();
+    let from = kind(map_type.next().expect("a map has a source"))?;
+    let to = kind(map_type.next().expect("a map has a destination"))?;
+    let ranges = map_tokens
+        .map(|range| {
+            let mut range_tokens = range.into_inner();
+            let mut next = || range_tokens.next().expect("a range has 3 numbers");
+            let destination_start = number(next())?;
+            let source_start = number(next())?;
+            let length = next();
+            let range_length = number(length.clone())?;
+            Range::try_from([destination_start, source_start, range_length])
+                .map_err(|e| Error::syntax(Day05::DAY, Span::pair(&length), e))
+        })
+        .collect::<Result<Vec<Range>, Error>>()?;
+    Ok(Map { from, to, ranges })
+}
+
+impl TryFrom<&str> for Input {
+    type Error = Error;
+
+    fn try_from(lines: &str) -> Result<Self, Self::Error> {
+        let mut input = InputParser::parse(Rule::input, lines)
+            .map_err(|e| Error::pest(Day05::DAY, &e, hint))?
+            .next()
+            .expect("a parsed input")
+            .into_inner();
+        let seeds = parse_seeds(input.next().expect("an input has seeds"))?;
+        let almanac = Almanac(
+            input
+                .filter(|pair| pair.as_rule() == Rule::map)
+                .map(parse_map)
+                .collect::<Result<Vec<Map>, Error>>()?,
+        );
+        Ok(Self { seeds, almanac })
+    }
+}
+
+/// Blank-line separated sections of the input, with the index of their
+/// first line.
+fn sections(input: &str) -> Vec<(usize, &str)> {
+    let mut sections = Vec::new();
+    // line index and byte offset of the current section
+    let mut start: Option<(usize, usize)> = None;
+    let mut offset = 0;
+    for (index, line) in input.split_inclusive('\n').enumerate() {
+        match (line.trim().is_empty(), start) {
+            (false, None) => start = Some((index, offset)),
+            (true, Some((first, begin))) => {
+                sections.push((first, &input[begin..offset]));
+                start = None;
+            }
+            _ => {}
+        }
+        offset += line.len();
+    }
+    if let Some((first, begin)) = start {
+        sections.push((first, &input[begin..]));
+    }
+    sections
+}
+
+/// Parses one section on its own, which must match `rule` entirely.
+fn parse_section<T>(
+    rule: Rule,
+    section: &str,
+    parse: impl Fn(Pair<Rule>) -> Result<T, Error>,
+) -> Result<T, Error> {
+    let pair = InputParser::parse(rule, section)
+        .map_err(|e| Error::pest(Day05::DAY, &e, hint))?
+        .next()
+        .expect("a parsed section");
+    let end = pair.as_str().len();
+    if !section[end..].trim().is_empty() {
+        let span = Span::at(section, end);
+        return Err(Error::syntax(
+            Day05::DAY,
+            span,
+            format!("unexpected text after {rule:?}"),
+        ));
+    }
+    parse(pair)
+}
+
+#[test]
+fn example01_seed14() {
+    let example = include_str!("../../input/day05/example01.txt");
+    let input = Input::try_from(example).unwrap();
+    let seed = Value(14, Kind::Seed);
+    let soil: Value = input
+        .almanac
+        .find_map(Kind::Seed)
+        .map(|map| map.translate(&seed))
+        .unwrap();
+    assert!(matches!(soil, Value(14, Kind::Soil)));
+    let fertilizer: Value = input
+        .almanac
+        .find_map(Kind::Soil)
+        .map(|map| map.translate(&soil))
+        .unwrap();
+    assert!(matches!(fertilizer, Value(53, Kind::Fertilizer)));
+    let water = input
+        .almanac
+        .find_map(Kind::Fertilizer)
+        .map(|map| map.translate(&fertilizer))
+        .unwrap();
+    assert!(matches!(water, Value(49, Kind::Water)));
+    let light = input
+        .almanac
+        .find_map(Kind::Water)
+        .map(|map| map.translate(&water))
+        .unwrap();
+    assert!(matches!(light, Value(42, Kind::Light)));
+    let temperature = input
+        .almanac
+        .find_map(Kind::Light)
+        .map(|map| map.translate(&light))
+        .unwrap();
+    assert!(matches!(temperature, Value(42, Kind::Temperature)));
+    let humidity = input
+        .almanac
+        .find_map(Kind::Temperature)
+        .map(|map| map.translate(&temperature))
+        .unwrap();
+    assert!(matches!(humidity, Value(43, Kind::Humidity)));
+    let location = input
+        .almanac
+        .find_map(Kind::Humidity)
+        .map(|map| map.translate(&humidity))
+        .unwrap();
+    assert!(matches!(location, Value(43, Kind::Location)));
+}
+
+#[test]
+fn example01_explanation() {
+    let example = include_str!("../../input/day05/example01.txt");
+    let input = Input::try_from(example).unwrap();
+    assert_eq!(
+        input.almanac.to_location(&Value(79, Kind::Seed)),
+        Ok(Value(82, Kind::Location))
+    );
+    assert_eq!(
+        input.almanac.to_location(&Value(14, Kind::Seed)),
+        Ok(Value(43, Kind::Location))
+    );
+    assert_eq!(
+        input.almanac.to_location(&Value(55, Kind::Seed)),
+        Ok(Value(86, Kind::Location))
+    );
+    assert_eq!(
+        input.almanac.to_location(&Value(13, Kind::Seed)),
+        Ok(Value(35, Kind::Location))
+    );
+}
+
+#[test]
+fn example01() {
+    let example = include_str!("../../input/day05/example01.txt");
+    let input = Input::try_from(example).unwrap();
+    assert_eq!(input.solve1(), Ok(35));
+}
+
+#[test]
+fn validate_sections() {
+    let example = include_str!("../../input/day05/example01.txt");
+    assert_eq!(
+        Day05::validate(example).to_string(),
+        "8 of 8 sections accepted, 0 rejected"
+    );
+    let edited = example
+        .replace("soil-to-fertilizer", "soil-to-fertiliser")
+        .replace("88 18 7", "88 18 x");
+    let report = Day05::validate(&edited);
+    assert_eq!(report.to_string(), "6 of 8 sections accepted, 2 rejected");
+    let spans: Vec<(usize, usize)> = report
+        .errors
+        .iter()
+        .filter_map(|e| match e {
+            Error::Syntax { span, .. } => Some((span.line, span.column)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(spans, vec![(7, 9), (19, 7)]);
+}
+
+#[test]
+fn validate_layout() {
+    use crate::policy::Policy;
+    use crate::solution::Day;
+    // layout errors reject the sections they fall in, once each
+    let example = include_str!("../../input/day05/example01.txt");
+    let crlf = example.replace('\n', "\r\n");
+    let report = Day::validate(&Day05, &crlf, Policy::Strict);
+    assert_eq!(report.to_string(), "0 of 8 sections accepted, 8 rejected");
+    let spaced = "seeds: 1\n\n\nseed-to-soil map:\n1 2 3\n\n\n";
+    let report = Day::validate(&Day05, spaced, Policy::Strict);
+    assert_eq!(report.to_string(), "0 of 2 sections accepted, 2 rejected");
+    assert_eq!(report.errors.len(), 2);
+}
+
+#[test]
+fn missing_map() {
+    let input = Input::try_from("seeds: 79\n\nseed-to-soil map:\n50 98 2\n").unwrap();
+    assert_eq!(
+        Day05::part1(&input).unwrap_err().to_string(),
+        "day 05: no map from Soil"
+    );
+}
+
+#[test]
+fn round_trip() {
+    let example = include_str!("../../input/day05/example01.txt");
+    let input = Day05::parse(example).unwrap();
+    assert_eq!(input.to_string(), example);
+}
+
+#[test]
+fn long_range() {
+    let example = "seeds: 1\n\nseed-to-soil map:\n9000000000000000000 0 1000000000000000000\n";
+    let Err(Error::Syntax { span, message, .. }) = Day05::parse(example) else {
+        panic!("the range should not fit");
+    };
+    assert_eq!((span.line, span.text.as_str()), (4, "1000000000000000000"));
+    assert_eq!(message, "range is too long");
+}
+
+#[test]
+fn json_ranges() {
+    use crate::solution::Day;
+
+    let range: Range = serde_json::from_str("[50, 98, 2]").unwrap();
+    assert_eq!(serde_json::to_string(&range).unwrap(), "[50,98,2]");
+    assert!(serde_json::from_str::<Range>("[50, -98, 2]").is_err());
+    let error = Day05
+        .parse_json(r#"{"seeds": [], "almanac": [{"from": "seed", "to": "soil", "ranges": [[9000000000000000000, 0, 1000000000000000000]]}]}"#)
+        .err()
+        .unwrap();
+    assert!(
+        error.to_string().ends_with(": range is too long"),
+        "{error}"
+    );
+}
+
+#[test]
+fn nom_matches_pest() {
+    let example = include_str!("../../input/day05/example01.txt");
+    let inputs = [
+        example.to_string(),
+        example.replace('\n', "\r\n"),
+        example.trim_end().to_string(),
+        example.replace("\n\n", "\n"),
+        example.replace("\n\n", "\n\n\n"),
+        example.replace("seeds: ", "seeds:"),
+        example.replace("soil-to-fertilizer", "soil-to-fertiliser"),
+        example.replace("88 18 7", "88 18 x"),
+        example.replace("88 18 7", "88 18"),
+        example.replace("88 18 7", "88 18 9223372036854775807"),
+        example.replace("88 18 7", "88 18 99999999999999999999"),
+        example.replace("seeds: 79", "seeds: 99999999999999999999"),
+        example.replace(" map:", "map:"),
+        "seeds: 1\n".to_string(),
+        String::new(),
+    ];
+    for input in &inputs {
+        crate::backend::assert_agree::<Day05>(input);
+    }
+}
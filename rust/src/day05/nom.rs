@@ -0,0 +1,148 @@
+//! A nom parser for the almanac, accepting the same inputs as `day05.pest`.
+
+use ::nom::branch::alt;
+use ::nom::bytes::complete::{tag, take_while};
+use ::nom::character::complete::digit1;
+use ::nom::combinator::{consumed, cut, eof, map, value};
+use ::nom::error::{context, ContextError, ErrorKind, ParseError, VerboseError};
+use ::nom::multi::{many0, many1};
+use ::nom::sequence::{preceded, separated_pair, terminated, tuple};
+use ::nom::{IResult, Offset};
+
+use super::{hint, Almanac, Day05, Input, Kind, Map, Range, Rule, Value};
+use crate::error::{Error, Span};
+use crate::solution::Solution;
+
+type Parsed<'a, T> = IResult<&'a str, T, VerboseError<&'a str>>;
+
+/// The grammar rule a context is named after.
+fn rule(context: &str) -> Option<Rule> {
+    match context {
+        "seeds" => Some(Rule::seeds),
+        "map" => Some(Rule::map),
+        "map_type" => Some(Rule::map_type),
+        "kind" => Some(Rule::kind),
+        "range" => Some(Rule::range),
+        "number" => Some(Rule::number),
+        "EOI" => Some(Rule::EOI),
+        _ => None,
+    }
+}
+
+/// A number after any number of spaces, like ` 79`. Numbers too large for
+/// an `i64` fail outright, rather than ending the list they are in.
+fn number(input: &str) -> Parsed<'_, i64> {
+    let (rest, digits) = context("number", preceded(take_while(|c| c == ' '), digit1))(input)?;
+    match digits.parse() {
+        Ok(number) => Ok((rest, number)),
+        Err(_) => {
+            let at = &input[input.offset(digits)..];
+            let error = VerboseError::from_error_kind(at, ErrorKind::MapRes);
+            Err(::nom::Err::Failure(VerboseError::add_context(
+                at, "number", error,
+            )))
+        }
+    }
+}
+
+/// Like pest's `NEWLINE`.
+fn newline(input: &str) -> Parsed<'_, &str> {
+    alt((tag("\n"), tag("\r\n"), tag("\r")))(input)
+}
+
+fn kind(input: &str) -> Parsed<'_, Kind> {
+    use Kind::*;
+
+    context(
+        "kind",
+        alt((
+            value(Seed, tag("seed")),
+            value(Soil, tag("soil")),
+            value(Fertilizer, tag("fertilizer")),
+            value(Water, tag("water")),
+            value(Light, tag("light")),
+            value(Temperature, tag("temperature")),
+            value(Humidity, tag("humidity")),
+            value(Location, tag("location")),
+        )),
+    )(input)
+}
+
+/// `seeds: 79 14 55 13`
+fn seeds(input: &str) -> Parsed<'_, Vec<Value>> {
+    context(
+        "seeds",
+        map(
+            terminated(preceded(tag("seeds:"), many1(number)), many1(newline)),
+            |numbers| numbers.into_iter().map(|n| Value(n, Kind::Seed)).collect(),
+        ),
+    )(input)
+}
+
+/// The numbers of a range like `50 98 2`, and the text of its length. A
+/// line starting with a number must be a whole range.
+fn range(input: &str) -> Parsed<'_, ([i64; 3], &str)> {
+    context(
+        "range",
+        map(
+            tuple((
+                number,
+                cut(terminated(
+                    tuple((number, consumed(number))),
+                    alt((newline, eof)),
+                )),
+            )),
+            |(destination_start, (source_start, (length, range_length)))| {
+                ([destination_start, source_start, range_length], length)
+            },
+        ),
+    )(input)
+}
+
+/// A map's kinds and ranges, before the ranges are checked.
+type RawMap<'a> = (Kind, Kind, Vec<([i64; 3], &'a str)>);
+
+/// `seed-to-soil map:` followed by at least one range
+fn raw_map(input: &str) -> Parsed<'_, RawMap<'_>> {
+    context(
+        "map",
+        map(
+            terminated(
+                tuple((
+                    context(
+                        "map_type",
+                        terminated(separated_pair(kind, tag("-to-"), kind), tag(" map:")),
+                    ),
+                    cut(preceded(newline, many1(range))),
+                )),
+                many0(newline),
+            ),
+            |((from, to), ranges)| (from, to, ranges),
+        ),
+    )(input)
+}
+
+/// Parses the whole input, with errors pointing into `text` like those of
+/// the pest parser.
+pub fn input(text: &str) -> Result<Input, Error> {
+    let (_, (seeds, maps)) = terminated(tuple((seeds, many1(raw_map))), context("EOI", eof))(text)
+        .map_err(|e| Error::nom(Day05::DAY, text, e, |c| rule(c).and_then(hint)))?;
+    let maps = maps
+        .into_iter()
+        .map(|(from, to, ranges)| {
+            let ranges = ranges
+                .into_iter()
+                .map(|(numbers, length)| {
+                    Range::try_from(numbers).map_err(|e| {
+                        Error::syntax(Day05::DAY, Span::at(text, text.offset(length)), e)
+                    })
+                })
+                .collect::<Result<Vec<Range>, Error>>()?;
+            Ok(Map { from, to, ranges })
+        })
+        .collect::<Result<Vec<Map>, Error>>()?;
+    Ok(Input {
+        seeds,
+        almanac: Almanac(maps),
+    })
+}
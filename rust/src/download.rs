@@ -0,0 +1,317 @@
+//! Downloading puzzle inputs into the inputs directory, which doubles as a
+//! cache: an input that is already on disk is never downloaded again.
+
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::input::{self, Source};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Minimum time between two requests to the server.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(5);
+
+const YEAR: u16 = 2023;
+const USER_AGENT: &str = "github.com/djanatyn/advent-of-code-2023";
+
+/// Name of the file recording when the last request was made, so that the
+/// rate limit also holds across separate runs.
+pub const STAMP_FILE: &str = ".last-request";
+
+#[derive(Debug)]
+pub enum FetchError {
+    MissingSession,
+    Status(u16, String),
+    Transport(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::MissingSession => write!(f, "no session token (set AOC_SESSION)"),
+            Self::Status(status, body) => write!(f, "server responded {status}: {}", body.trim()),
+            Self::Transport(e) => write!(f, "request failed: {e}"),
+            Self::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for FetchError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<ureq::Error> for FetchError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, response) => {
+                Self::Status(status, response.into_string().unwrap_or_default())
+            }
+            ureq::Error::Transport(e) => Self::Transport(e.to_string()),
+        }
+    }
+}
+
+/// Spaces out requests, remembering the last one in a stamp file.
+#[derive(Debug)]
+pub struct RateLimiter {
+    interval: Duration,
+    stamp: PathBuf,
+}
+
+impl RateLimiter {
+    pub fn new(interval: Duration, stamp: PathBuf) -> Self {
+        RateLimiter { interval, stamp }
+    }
+
+    fn last_request(&self) -> Option<SystemTime> {
+        let millis = fs::read_to_string(&self.stamp).ok()?.trim().parse().ok()?;
+        Some(UNIX_EPOCH + Duration::from_millis(millis))
+    }
+
+    /// Sleeps until the interval since the last request has passed, then
+    /// records a new request.
+    pub fn wait(&self) -> io::Result<()> {
+        if let Some(last) = self.last_request() {
+            let elapsed = last.elapsed().unwrap_or(Duration::ZERO);
+            if elapsed < self.interval {
+                thread::sleep(self.interval - elapsed);
+            }
+        }
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or(Duration::ZERO);
+        if let Some(dir) = self.stamp.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.stamp, now.as_millis().to_string())
+    }
+}
+
+/// Where an input came from.
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    limiter: RateLimiter,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: Option<String>, limiter: RateLimiter) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.filter(|session| !session.is_empty()),
+            limiter,
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}/{YEAR}/day/{path}", self.base_url)
+    }
+
+    /// A GET request with the session cookie, after waiting for the rate limit.
+    pub fn get(&self, path: &str) -> Result<ureq::Response, FetchError> {
+        let session = self.session.as_ref().ok_or(FetchError::MissingSession)?;
+        self.limiter.wait()?;
+        let response = self
+            .agent
+            .get(&self.url(path))
+            .set("Cookie", &format!("session={session}"))
+            .call()?;
+        Ok(response)
+    }
+
+    /// A form POST with the session cookie, after waiting for the rate
+    /// limit. Returns the response body.
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, FetchError> {
+        let session = self.session.as_ref().ok_or(FetchError::MissingSession)?;
+        self.limiter.wait()?;
+        let response = self
+            .agent
+            .post(&self.url(path))
+            .set("Cookie", &format!("session={session}"))
+            .send_form(form)?;
+        Ok(response.into_string()?)
+    }
+
+    /// The day's input from `input_dir`, downloading it first if needed.
+    pub fn fetch(&self, input_dir: &Path, day: u8) -> Result<Fetched, FetchError> {
+        let Source::File(path) = Source::day(input_dir, day) else {
+            unreachable!("day inputs are files");
+        };
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+        let mut input = String::new();
+        self.get(&format!("{day}/input"))?
+            .into_reader()
+            .read_to_string(&mut input)?;
+        // write to a temporary file first so an interrupted download never
+        // looks like a cached input
+        fs::create_dir_all(input::day_dir(input_dir, day))?;
+        let partial = path.with_extension("txt.partial");
+        fs::write(&partial, input)?;
+        fs::rename(&partial, &path)?;
+        Ok(Fetched::Downloaded(path))
+    }
+}
+
+/// A local HTTP server answering every request with a canned response, for
+/// exercising [`Client`] without touching the real site.
+#[cfg(test)]
+pub mod stub {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc::{self, Receiver};
+    use std::thread;
+
+    /// A request as seen by the stub server.
+    #[derive(Debug)]
+    pub struct Request {
+        pub line: String,
+        pub headers: Vec<String>,
+        pub body: String,
+    }
+
+    /// Serves `responses` (status, body) in order, one per connection.
+    /// Returns the base URL and a channel of received requests.
+    pub fn serve(responses: Vec<(u16, String)>) -> (String, Receiver<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let mut headers = Vec::new();
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    headers.push(header.trim().to_string());
+                }
+                let length = headers
+                    .iter()
+                    .find_map(|h| {
+                        h.to_lowercase()
+                            .strip_prefix("content-length:")
+                            .map(String::from)
+                    })
+                    .and_then(|length| length.trim().parse().ok())
+                    .unwrap_or(0);
+                let mut request_body = vec![0; length];
+                reader.read_exact(&mut request_body).unwrap();
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+                let request = Request {
+                    line: line.trim().to_string(),
+                    headers,
+                    body: String::from_utf8(request_body).unwrap(),
+                };
+                if sender.send(request).is_err() {
+                    break;
+                }
+            }
+        });
+        (url, receiver)
+    }
+}
+
+#[cfg(test)]
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn downloads_once() {
+    let dir = temp_dir("download");
+    let (url, requests) = stub::serve(vec![(200, "1abc2\n".into())]);
+    let limiter = RateLimiter::new(Duration::ZERO, dir.join(STAMP_FILE));
+    let client = Client::new(&url, Some("secret".into()), limiter);
+
+    let path = dir.join("day01/input.txt");
+    assert_eq!(
+        client.fetch(&dir, 1).unwrap(),
+        Fetched::Downloaded(path.clone())
+    );
+    let request = requests.recv().unwrap();
+    assert_eq!(request.line, "GET /2023/day/1/input HTTP/1.1");
+    assert!(request
+        .headers
+        .iter()
+        .any(|h| h == "Cookie: session=secret"));
+    assert_eq!(fs::read_to_string(&path).unwrap(), "1abc2\n");
+
+    // the stub only answers once, so this must come from the cache
+    assert_eq!(client.fetch(&dir, 1).unwrap(), Fetched::Cached(path));
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn errors_are_not_cached() {
+    let dir = temp_dir("download-error");
+    let (url, _requests) = stub::serve(vec![(400, "Puzzle inputs differ by user.".into())]);
+    let limiter = RateLimiter::new(Duration::ZERO, dir.join(STAMP_FILE));
+    let client = Client::new(&url, Some("expired".into()), limiter);
+
+    assert!(matches!(
+        client.fetch(&dir, 2),
+        Err(FetchError::Status(400, body)) if body == "Puzzle inputs differ by user."
+    ));
+    assert!(!dir.join("day02/input.txt").exists());
+    let client = Client::new(
+        &url,
+        None,
+        RateLimiter::new(Duration::ZERO, dir.join(STAMP_FILE)),
+    );
+    assert!(matches!(
+        client.fetch(&dir, 2),
+        Err(FetchError::MissingSession)
+    ));
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn rate_limit() {
+    let dir = temp_dir("rate-limit");
+    let limiter = RateLimiter::new(Duration::from_millis(200), dir.join(STAMP_FILE));
+    let start = std::time::Instant::now();
+    limiter.wait().unwrap();
+    assert!(start.elapsed() < Duration::from_millis(200));
+    // a second limiter sharing the stamp file still waits (the stamp only
+    // has millisecond precision)
+    let limiter = RateLimiter::new(Duration::from_millis(200), dir.join(STAMP_FILE));
+    limiter.wait().unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(199));
+
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,389 @@
+//! The error shared by every day, pointing at the offending input.
+
+use std::fmt;
+
+use nom::error::{VerboseError, VerboseErrorKind};
+use pest::error::{ErrorVariant, LineColLocation};
+use pest::iterators::Pair;
+use pest::RuleType;
+
+use crate::solution::Part;
+
+/// A position in the puzzle input (1-based), the text found there and the
+/// whole line it is on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub source: String,
+}
+
+impl Span {
+    /// The whole of a line, e.g. one that can't be solved.
+    pub fn line(index: usize, line: &str) -> Self {
+        Span {
+            line: index + 1,
+            column: 1,
+            text: line.to_string(),
+            source: line.to_string(),
+        }
+    }
+
+    /// The text matched by a pest pair, without surrounding whitespace.
+    pub fn pair<R: RuleType>(pair: &Pair<R>) -> Self {
+        let (line, column) = pair.line_col();
+        let span = pair.as_span();
+        let source = span.lines().next().unwrap_or_default();
+        let text = pair.as_str().trim_end();
+        let spaces = text.chars().take_while(|c| c.is_whitespace()).count();
+        Span {
+            line,
+            column: column + spaces,
+            text: text.trim_start().to_string(),
+            source: source.trim_end_matches(['\r', '\n']).to_string(),
+        }
+    }
+
+    /// The word at a byte offset of `input`.
+    pub fn at(input: &str, offset: usize) -> Self {
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        let line = input[line_start..].lines().next().unwrap_or_default();
+        let column = input[line_start..offset].chars().count() + 1;
+        Span::word(before.matches('\n').count() + 1, column, line)
+    }
+
+    /// Like [`Span::at`], for input that may not be UTF-8.
+    pub fn at_bytes(input: &[u8], offset: usize) -> Self {
+        let before = String::from_utf8_lossy(&input[..offset]);
+        let after = String::from_utf8_lossy(&input[offset..]);
+        Span::at(&format!("{before}{after}"), before.len())
+    }
+
+    /// The first word at or after `column` of `source`, or nothing at the
+    /// end of the line.
+    fn word(line: usize, column: usize, source: &str) -> Self {
+        // pest shows the line break at the end of the line as `␊`
+        let source = source.trim_end_matches(['␊', '\r', '\n']);
+        let rest = source.chars().skip(column.saturating_sub(1));
+        // point at the word rather than the spaces before it
+        let spaces = rest.clone().take_while(|c| c.is_whitespace()).count();
+        let column = column + spaces;
+        let text = rest
+            .skip(spaces)
+            .take_while(|c| !c.is_whitespace())
+            .collect();
+        Span {
+            line,
+            column,
+            text,
+            source: source.to_string(),
+        }
+    }
+
+    /// The source line with a caret under the span, indented by the width
+    /// of the line number.
+    fn snippet(&self, label: &str) -> String {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let indent = " ".repeat(self.column.saturating_sub(1));
+        // a multi-line span is only underlined on its first line
+        let width = self
+            .text
+            .lines()
+            .next()
+            .map_or(0, |text| text.chars().count());
+        let carets = "^".repeat(width.max(1));
+        format!(
+            "{gutter} |\n{number} | {}\n{gutter} | {indent}{carets} {label}",
+            self.source
+        )
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Span {
+            line, column, text, ..
+        } = self;
+        if text.is_empty() {
+            write!(f, "line {line}, column {column}, at end of line")
+        } else {
+            write!(f, "line {line}, column {column}, at {text:?}")
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// The input doesn't have the expected format.
+    Syntax {
+        day: u8,
+        span: Box<Span>,
+        message: String,
+        /// Grammar rules that would have matched.
+        expected: Vec<String>,
+        /// What the expected input looks like.
+        hint: Option<&'static str>,
+    },
+    /// The input is well-formed but has no answer.
+    Invalid {
+        day: u8,
+        span: Option<Box<Span>>,
+        message: String,
+    },
+    Unimplemented {
+        day: u8,
+        part: Part,
+    },
+}
+
+impl Error {
+    pub fn syntax(day: u8, span: Span, message: impl Into<String>) -> Self {
+        Error::Syntax {
+            day,
+            span: Box::new(span),
+            message: message.into(),
+            expected: Vec::new(),
+            hint: None,
+        }
+    }
+
+    pub fn invalid(day: u8, span: Option<Span>, message: impl Into<String>) -> Self {
+        Error::Invalid {
+            day,
+            span: span.map(Box::new),
+            message: message.into(),
+        }
+    }
+
+    /// An answer too large for its type.
+    pub fn overflow(day: u8) -> Self {
+        Error::invalid(day, None, "the answer overflows")
+    }
+
+    /// A pest error, pointing at the word where parsing failed. `hint`
+    /// describes what a rule matches, for the rules worth explaining: every
+    /// grammar has a `hint` function next to it, which tells users what the
+    /// rules look like when their input doesn't parse.
+    pub fn pest<R: RuleType>(
+        day: u8,
+        error: &pest::error::Error<R>,
+        hint: impl Fn(R) -> Option<&'static str>,
+    ) -> Self {
+        let (line, column) = match error.line_col {
+            LineColLocation::Pos(position) => position,
+            LineColLocation::Span(start, _) => start,
+        };
+        let rules = match &error.variant {
+            ErrorVariant::ParsingError { positives, .. } => positives.clone(),
+            ErrorVariant::CustomError { .. } => Vec::new(),
+        };
+        Error::Syntax {
+            day,
+            span: Box::new(Span::word(line, column, error.line())),
+            message: error.variant.message().into_owned(),
+            expected: rules.iter().map(|rule| format!("{rule:?}")).collect(),
+            hint: rules.into_iter().find_map(hint),
+        }
+    }
+
+    /// A nom error, pointing at the word where parsing failed in `input`,
+    /// the text given to the parser. The contexts of the parsers that failed
+    /// are the expected rules, and `hint` describes what a context matches.
+    pub fn nom(
+        day: u8,
+        input: &str,
+        error: nom::Err<VerboseError<&str>>,
+        hint: impl Fn(&str) -> Option<&'static str>,
+    ) -> Self {
+        let errors = match error {
+            nom::Err::Error(e) | nom::Err::Failure(e) => e.errors,
+            // only streaming parsers need more input
+            nom::Err::Incomplete(_) => Vec::new(),
+        };
+        // the first error is the innermost, where parsing failed
+        let rest = errors.first().map_or("", |(rest, _)| rest);
+        let contexts: Vec<&str> = errors
+            .iter()
+            .filter_map(|(_, kind)| match kind {
+                VerboseErrorKind::Context(context) => Some(*context),
+                _ => None,
+            })
+            .collect();
+        let message = match (contexts.first(), errors.first()) {
+            (Some(context), _) => format!("expected {context}"),
+            (None, Some((_, VerboseErrorKind::Char(c)))) => format!("expected {c:?}"),
+            (None, Some((_, VerboseErrorKind::Nom(kind)))) => {
+                format!("unexpected input ({})", kind.description())
+            }
+            (None, Some((_, VerboseErrorKind::Context(_)))) => unreachable!("a context"),
+            (None, None) => "unexpected end of input".to_string(),
+        };
+        Error::Syntax {
+            day,
+            span: Box::new(Span::at(input, input.len() - rest.len())),
+            message,
+            expected: contexts
+                .first()
+                .map(|c| c.to_string())
+                .into_iter()
+                .collect(),
+            hint: contexts.into_iter().find_map(hint),
+        }
+    }
+
+    /// A pre-parsed input that isn't the JSON of the day's parsed input.
+    pub fn json(day: u8, input: &str, error: &serde_json::Error) -> Self {
+        let (line, column) = (error.line(), error.column());
+        // errors found after reading a whole value have no position
+        if line == 0 {
+            return Error::invalid(day, None, error.to_string());
+        }
+        // serde_json appends the position, which the span already shows
+        let message = error.to_string();
+        let position = format!(" at line {line} column {column}");
+        let message = message.strip_suffix(&position).unwrap_or(&message);
+        // the column is that of the last character read
+        let source = input.lines().nth(line - 1).unwrap_or_default();
+        let text = source.chars().nth(column.saturating_sub(1));
+        let span = Span {
+            line,
+            column: column.max(1),
+            text: text.map(String::from).unwrap_or_default(),
+            source: source.to_string(),
+        };
+        Error::syntax(day, span, message)
+    }
+
+    /// A TOML file (like a day 1 dictionary) that can't be read.
+    pub fn toml(day: u8, input: &str, error: &toml::de::Error) -> Self {
+        // the message has no position, which the span shows, but may go on
+        // over a few lines
+        let message = error.message().trim_end().replace('\n', ": ");
+        match error.span() {
+            Some(span) => Error::syntax(day, Span::at(input, span.start), message),
+            None => Error::invalid(day, None, message),
+        }
+    }
+
+    /// Moves an error found while parsing a single line to that line of the
+    /// whole input (`index` is 0-based).
+    pub fn on_line(self, index: usize) -> Self {
+        self.map_line(|line| line + index)
+    }
+
+    /// Changes the (1-based) line the error points at.
+    pub fn map_line(mut self, f: impl FnOnce(usize) -> usize) -> Self {
+        match &mut self {
+            Error::Syntax { span, .. }
+            | Error::Invalid {
+                span: Some(span), ..
+            } => {
+                span.line = f(span.line);
+            }
+            Error::Invalid { span: None, .. } | Error::Unimplemented { .. } => {}
+        }
+        self
+    }
+
+    /// The (1-based) line the error points at, if any.
+    pub fn line(&self) -> Option<usize> {
+        match self {
+            Error::Syntax { span, .. }
+            | Error::Invalid {
+                span: Some(span), ..
+            } => Some(span.line),
+            Error::Invalid { span: None, .. } | Error::Unimplemented { .. } => None,
+        }
+    }
+
+    pub fn day(&self) -> u8 {
+        match self {
+            Error::Syntax { day, .. }
+            | Error::Invalid { day, .. }
+            | Error::Unimplemented { day, .. } => *day,
+        }
+    }
+}
+
+/// The alternate form (`{:#}`) adds the offending line, a caret under the
+/// offending text, and for syntax errors the expected rules and a hint.
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Syntax {
+                day, span, message, ..
+            } => write!(f, "day {day:02}, {span}: {message}")?,
+            Error::Invalid {
+                day,
+                span: Some(span),
+                message,
+            } => write!(f, "day {day:02}, {span}: {message}")?,
+            Error::Invalid {
+                day,
+                span: None,
+                message,
+            } => write!(f, "day {day:02}: {message}")?,
+            Error::Unimplemented { day, part } => {
+                write!(f, "day {day:02} part {part} is not implemented")?
+            }
+        }
+        if !f.alternate() {
+            return Ok(());
+        }
+        match self {
+            Error::Syntax {
+                span,
+                message,
+                expected,
+                hint,
+                ..
+            } => {
+                write!(f, "\n{}", span.snippet(message))?;
+                if !expected.is_empty() {
+                    write!(f, "\n  = expected: {}", expected.join(", "))?;
+                }
+                if let Some(hint) = hint {
+                    write!(f, "\n  = hint: {hint}")?;
+                }
+            }
+            Error::Invalid {
+                span: Some(span),
+                message,
+                ..
+            } => write!(f, "\n{}", span.snippet(message))?,
+            Error::Invalid { span: None, .. } | Error::Unimplemented { .. } => {}
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[test]
+fn spans() {
+    let error = Error::syntax(
+        2,
+        Span {
+            line: 1,
+            column: 9,
+            text: "x".into(),
+            source: "Game 1: x blue".into(),
+        },
+        "expected count",
+    );
+    assert_eq!(
+        error.clone().on_line(3).to_string(),
+        "day 02, line 4, column 9, at \"x\": expected count"
+    );
+    assert_eq!(
+        format!("{:#}", error.on_line(3)),
+        "day 02, line 4, column 9, at \"x\": expected count\n  |\n4 | Game 1: x blue\n  |         ^ expected count"
+    );
+    assert_eq!(
+        Error::invalid(5, None, "no seeds").to_string(),
+        "day 05: no seeds"
+    );
+}
@@ -0,0 +1,256 @@
+//! Example inputs and their expected answers, extracted from a saved puzzle
+//! page.
+//!
+//! Each day's examples are listed in `input/dayNN/examples.txt` as
+//! `file part answer`:
+//!
+//! ```text
+//! # example part answer
+//! example01.txt 1 142
+//! example02.txt 2 281
+//! ```
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use regex::Regex;
+
+use crate::input;
+use crate::solution::Part;
+
+/// Name of the manifest inside a day's input directory.
+pub const MANIFEST: &str = "examples.txt";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expected {
+    pub file: String,
+    pub part: Part,
+    pub answer: String,
+}
+
+/// Expected answers for a day's example files.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Manifest(pub Vec<Expected>);
+
+impl Manifest {
+    /// The expected answers in a day's manifest (see [`input::load`]).
+    pub fn load(day_dir: &Path) -> io::Result<Self> {
+        input::load(&day_dir.join(MANIFEST))
+    }
+}
+
+impl FromStr for Manifest {
+    type Err = String;
+
+    fn from_str(contents: &str) -> Result<Self, Self::Err> {
+        let mut expected = Vec::new();
+        for (index, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let &[file, part, answer] = fields.as_slice() else {
+                return Err(format!("line {}: expected 3 fields", index + 1));
+            };
+            let part = part
+                .parse::<Part>()
+                .map_err(|e| format!("line {}: {e}", index + 1))?;
+            expected.push(Expected {
+                file: file.into(),
+                part,
+                answer: answer.into(),
+            });
+        }
+        Ok(Manifest(expected))
+    }
+}
+
+impl fmt::Display for Manifest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "# example part answer")?;
+        for Expected { file, part, answer } in &self.0 {
+            writeln!(f, "{file} {part} {answer}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Examples and answers found in a puzzle page.
+#[derive(Debug, Default, PartialEq)]
+pub struct Extracted {
+    /// Distinct `<pre><code>` blocks, in page order.
+    pub examples: Vec<String>,
+    pub manifest: Manifest,
+}
+
+/// Name of the n-th (0-based) example file.
+pub fn example_file(index: usize) -> String {
+    format!("example{:02}.txt", index + 1)
+}
+
+/// The text of an HTML fragment, without tags and with entities decoded.
+pub(crate) fn unescape(html: &str) -> String {
+    let tags = Regex::new(r"<[^>]*>").unwrap();
+    let entities = Regex::new(r"&(#x[0-9a-fA-F]+|#[0-9]+|[a-z]+);").unwrap();
+    let text = tags.replace_all(html, "");
+    entities
+        .replace_all(&text, |captures: &regex::Captures| {
+            let entity = &captures[1];
+            let decoded = match entity {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                _ => entity
+                    .strip_prefix("#x")
+                    .map(|hex| u32::from_str_radix(hex, 16))
+                    .or_else(|| entity.strip_prefix('#').map(str::parse::<u32>))
+                    .and_then(Result::ok)
+                    .and_then(char::from_u32),
+            };
+            decoded.map_or(captures[0].to_string(), String::from)
+        })
+        .into_owned()
+}
+
+/// Finds example blocks and the expected answer of each part.
+///
+/// Each part of a puzzle is an `<article class="day-desc">`. Its answer is
+/// the last emphasized `<code><em>` in the article, and its example is the
+/// first `<pre><code>` block of the article, or the previous part's example
+/// when the article has none.
+pub fn extract(html: &str) -> Extracted {
+    let articles = Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#).unwrap();
+    let blocks = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    let answers = Regex::new(r"(?s)<code><em>(.*?)</em></code>").unwrap();
+
+    let mut extracted = Extracted::default();
+    let mut example: Option<usize> = None;
+    for (article, part) in articles.captures_iter(html).zip(Part::BOTH) {
+        let article = &article[1];
+        let mut first = None;
+        for block in blocks.captures_iter(article) {
+            let text = unescape(&block[1]);
+            let index = match extracted.examples.iter().position(|e| *e == text) {
+                Some(index) => index,
+                None => {
+                    extracted.examples.push(text);
+                    extracted.examples.len() - 1
+                }
+            };
+            first.get_or_insert(index);
+        }
+        example = first.or(example);
+        let answer = answers
+            .captures_iter(article)
+            .last()
+            .map(|answer| unescape(&answer[1]));
+        if let (Some(index), Some(answer)) = (example, answer) {
+            extracted.manifest.0.push(Expected {
+                file: example_file(index),
+                part,
+                answer,
+            });
+        }
+    }
+    extracted
+}
+
+/// Writes extracted examples and their manifest into a day's input
+/// directory. Existing files are only replaced if their contents match.
+pub fn write(input_dir: &Path, day: u8, extracted: &Extracted) -> io::Result<Vec<PathBuf>> {
+    let dir = input::day_dir(input_dir, day);
+    let mut files: Vec<(PathBuf, String)> = extracted
+        .examples
+        .iter()
+        .enumerate()
+        .map(|(index, example)| (dir.join(example_file(index)), example.clone()))
+        .collect();
+    files.push((dir.join(MANIFEST), extracted.manifest.to_string()));
+    for (path, contents) in &files {
+        match fs::read_to_string(path) {
+            Ok(existing) if existing != *contents => {
+                return Err(io::Error::new(
+                    io::ErrorKind::AlreadyExists,
+                    format!("{} already exists with different contents", path.display()),
+                ))
+            }
+            Ok(_) => {}
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+    }
+    fs::create_dir_all(&dir)?;
+    for (path, contents) in &files {
+        fs::write(path, contents)?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+#[test]
+fn extract_page() {
+    let html = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+</code></pre>
+<p>The values are <code>12</code> and <code>38</code>, producing <code><em>50</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>54916</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Using the same example, <code>a &lt; b</code> gives <code><em>4&amp;2</em></code>.</p>
+</article>
+</main>"#;
+    let extracted = extract(html);
+    assert_eq!(extracted.examples, vec!["1abc2\npqr3stu8vwx\n"]);
+    assert_eq!(
+        extracted.manifest,
+        Manifest(vec![
+            Expected {
+                file: "example01.txt".into(),
+                part: Part::One,
+                answer: "50".into(),
+            },
+            Expected {
+                file: "example01.txt".into(),
+                part: Part::Two,
+                answer: "4&2".into(),
+            },
+        ])
+    );
+}
+
+#[test]
+fn unescape_entities() {
+    assert_eq!(
+        unescape("<em>&lt;a&gt;</em> &#65;&#x42; &nbsp;"),
+        "<a> AB &nbsp;"
+    );
+}
+
+/// Every manifest entry must match what the day's solver computes.
+#[test]
+fn manifests() {
+    let input_dir = Path::new(input::DEFAULT_DIR);
+    for solver in crate::DAYS {
+        let day = solver.number();
+        let dir = input::day_dir(input_dir, day);
+        for Expected { file, part, answer } in Manifest::load(&dir).unwrap().0 {
+            let example = fs::read_to_string(dir.join(&file)).unwrap();
+            let parsed = solver
+                .parse_input(&example, crate::policy::Policy::Strict)
+                .unwrap();
+            match solver.solve(&parsed, part) {
+                Ok(solved) => assert_eq!(solved, answer, "day {day:02} part {part} of {file}"),
+                Err(crate::error::Error::Unimplemented { .. }) => {}
+                Err(e) => panic!("{e}"),
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! Locating and reading puzzle inputs at runtime.
+
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use clap::ValueEnum;
+
+/// The `input/` directory at the root of the repository.
+pub const DEFAULT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../input");
+
+/// Where to read a puzzle input from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// The puzzle input for a day, e.g. `<dir>/day04/input.txt`.
+    pub fn day(dir: &Path, day: u8) -> Self {
+        Self::File(day_dir(dir, day).join("input.txt"))
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Self::File(path) => fs::read_to_string(path),
+        }
+    }
+
+    /// A reader for streaming the input rather than reading it whole.
+    pub fn open(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            Self::Stdin => Ok(Box::new(io::stdin().lock())),
+            Self::File(path) => Ok(Box::new(BufReader::new(fs::File::open(path)?))),
+        }
+    }
+}
+
+/// Reads and parses a file that the CLI keeps up to date, like known
+/// answers. A missing file reads as empty, as nothing has been written yet.
+pub fn load<T: FromStr<Err = String>>(path: &Path) -> io::Result<T> {
+    let contents = match fs::read_to_string(path) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        contents => contents?,
+    };
+    contents
+        .parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// How a puzzle input is written.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Puzzle text, as downloaded.
+    #[default]
+    Text,
+    /// A parsed input as JSON, as printed by `aoc parse`.
+    Json,
+}
+
+/// `-` reads from stdin, anything else is a path.
+impl FromStr for Source {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err("empty path".into()),
+            "-" => Ok(Self::Stdin),
+            path => Ok(Self::File(path.into())),
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Stdin => write!(f, "<stdin>"),
+            Self::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Directory holding a day's input and examples, e.g. `<dir>/day04`.
+pub fn day_dir(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day{day:02}"))
+}
+
+#[test]
+fn sources() {
+    assert_eq!("-".parse(), Ok(Source::Stdin));
+    assert_eq!(
+        "input/day01/example01.txt".parse(),
+        Ok(Source::File("input/day01/example01.txt".into()))
+    );
+    assert_eq!(
+        Source::day(Path::new("input"), 4),
+        Source::File("input/day04/input.txt".into())
+    );
+}
@@ -0,0 +1,37 @@
+//! Advent of Code 2023 solutions, one module per day.
+
+use solution::Day;
+
+pub mod answers;
+pub mod backend;
+pub mod bench;
+#[cfg(feature = "fast")]
+pub mod bytes;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod download;
+pub mod error;
+pub mod examples;
+pub mod input;
+pub mod policy;
+pub mod scaffold;
+pub mod solution;
+pub mod stream;
+pub mod submit;
+pub mod validate;
+
+/// Every day with a solver, in order.
+pub static DAYS: &[&dyn Day] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+];
+
+pub fn find_day(day: u8) -> Option<&'static dyn Day> {
+    DAYS.iter().copied().find(|solver| solver.number() == day)
+}
@@ -1,3 +1,682 @@
-fn main() {
-    println!("Hello, world!");
+use std::env;
+use std::fmt;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use advent_of_code_2023::answers::{self, Answers, InputHash};
+use advent_of_code_2023::backend::Backend;
+use advent_of_code_2023::bench::{self, Format};
+use advent_of_code_2023::day01::{self, Dictionary, Digits, NoDigits, Options};
+use advent_of_code_2023::download::{self, Client, Fetched, RateLimiter};
+use advent_of_code_2023::error::Error;
+use advent_of_code_2023::examples;
+use advent_of_code_2023::input::Format as InputFormat;
+use advent_of_code_2023::input::{self, Source};
+use advent_of_code_2023::policy::Policy;
+use advent_of_code_2023::scaffold;
+use advent_of_code_2023::solution::Part;
+use advent_of_code_2023::stream::StreamError;
+use advent_of_code_2023::submit::{self, SubmitError, Verdict};
+use advent_of_code_2023::{find_day, DAYS};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2023 solutions")]
+struct Cli {
+    /// Directory holding `dayNN/input.txt` puzzle inputs.
+    #[arg(long, global = true, env = "AOC_INPUT_DIR", default_value = input::DEFAULT_DIR)]
+    input_dir: PathBuf,
+    /// How to treat CRLF line endings, tabs, trailing whitespace and blank
+    /// lines in inputs.
+    #[arg(long, global = true, env = "AOC_POLICY", value_enum, default_value_t)]
+    policy: Policy,
+    /// The parser of days that have several; `bench` times them all.
+    #[arg(long, global = true, env = "AOC_BACKEND", value_enum, default_value_t)]
+    backend: Backend,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day's puzzle input.
+    Run {
+        /// Day of the puzzle (1-25).
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Only solve one part (defaults to both).
+        #[arg(long)]
+        part: Option<Part>,
+        /// Read the puzzle input from a file, or `-` for stdin.
+        #[arg(long)]
+        input: Option<Source>,
+        /// How the input is written.
+        #[arg(long, value_enum, default_value_t)]
+        input_format: InputFormat,
+        /// Solve the input a line at a time as it is read, for inputs too
+        /// large to hold in memory (days 1, 2 and 4).
+        #[arg(long, conflicts_with = "input_format")]
+        stream: bool,
+        /// Solve day 1's part 2 with the digit words of a TOML dictionary.
+        #[arg(long, conflicts_with_all = ["input_format", "stream"])]
+        dictionary: Option<PathBuf>,
+        /// Show the digits and digit words found on every line of day 1,
+        /// and the first and last chosen.
+        #[arg(long, conflicts_with_all = ["input_format", "stream", "dictionary"])]
+        explain: bool,
+        /// What to do with day 1 lines without digits, reporting how many
+        /// there were (defaults to failing).
+        #[arg(long, value_enum, conflicts_with_all = ["input_format", "stream"])]
+        no_digits: Option<NoDigits>,
+        /// Which characters are digits on day 1 (defaults to `0` to `9`).
+        #[arg(long, value_enum, conflicts_with_all = ["input_format", "stream"])]
+        digits: Option<Digits>,
+    },
+    /// Parse a day's puzzle input and print the parsed input.
+    Parse {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Parse a file, or `-` for stdin, instead of the day's input.
+        #[arg(long)]
+        input: Option<Source>,
+        /// How to print the parsed input.
+        #[arg(long, value_enum, default_value_t = Output::Json)]
+        format: Output,
+    },
+    /// Re-solve every day's input and compare against the known answers.
+    Check {
+        /// Record answers that are not known yet.
+        #[arg(long)]
+        record: bool,
+    },
+    /// Time parsing and each part of every day, or of a single day.
+    Bench {
+        /// Only benchmark this day.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Number of timed runs of each phase.
+        #[arg(long, default_value_t = 10)]
+        iterations: usize,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Benchmark a file, or `-` for stdin, instead of the day's input.
+        #[arg(long, requires = "day")]
+        input: Option<Source>,
+    },
+    /// Parse every line or section of an input and report all errors.
+    Validate {
+        /// Only validate this day.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Validate a file, or `-` for stdin, instead of the day's input.
+        #[arg(long, requires = "day")]
+        input: Option<Source>,
+    },
+    /// Generate and register the solver module and example input for a day.
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Also generate a pest grammar for the input.
+        #[arg(long)]
+        pest: bool,
+    },
+    /// Extract example inputs and answers from a saved puzzle page.
+    Extract {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// The puzzle page, saved as HTML.
+        page: Source,
+    },
+    /// Download puzzle inputs that aren't in the inputs directory yet.
+    Fetch {
+        /// Days to fetch (defaults to every registered day).
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        days: Vec<u8>,
+        #[command(flatten)]
+        server: Server,
+    },
+    /// Solve a day's input and submit the answer of one part.
+    Submit {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        part: Part,
+        /// Submit this answer instead of solving the day's input.
+        #[arg(long)]
+        answer: Option<String>,
+        #[command(flatten)]
+        server: Server,
+    },
+}
+
+/// How `parse` prints a parsed input.
+#[derive(Clone, Copy, ValueEnum)]
+enum Output {
+    /// Pretty-printed JSON, which `run --input-format json` reads back.
+    Json,
+}
+
+#[derive(Args)]
+struct Server {
+    /// Session cookie of a logged in Advent of Code account.
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+    #[arg(long, env = "AOC_BASE_URL", default_value = download::DEFAULT_BASE_URL)]
+    base_url: String,
+}
+
+impl Server {
+    fn client(self, input_dir: &Path) -> Client {
+        let limiter = RateLimiter::new(
+            download::DEFAULT_INTERVAL,
+            input_dir.join(download::STAMP_FILE),
+        );
+        Client::new(&self.base_url, self.session, limiter)
+    }
+}
+
+#[derive(Debug)]
+enum RunError {
+    UnimplementedDay(u8),
+    Input(Source, io::Error),
+    StreamStdin,
+    DayOne(&'static str, u8),
+    Dictionary(PathBuf, Error),
+    Solve(Error),
+    Answers(PathBuf, io::Error),
+    Scaffold(u8, io::Error),
+    Extract(u8, io::Error),
+    Fetch(u8, download::FetchError),
+    Submit(u8, Part, SubmitError),
+    NotAccepted(u8, Part, Verdict),
+    Check { drifted: usize, failed: usize },
+    Validate { rejected: usize },
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnimplementedDay(day) => write!(f, "day {day:02} is not implemented"),
+            Self::Input(source, e) => write!(f, "failed to read {source}: {e}"),
+            Self::StreamStdin => {
+                write!(
+                    f,
+                    "stdin can only be streamed once; choose a part with --part"
+                )
+            }
+            Self::DayOne(option, day) => write!(f, "{option} is only for day 01, not day {day:02}"),
+            // keep the diagnostic's snippet when asked for with `{:#}`
+            Self::Dictionary(path, e) if f.alternate() => {
+                write!(f, "invalid dictionary {}: {e:#}", path.display())
+            }
+            Self::Dictionary(path, e) => write!(f, "invalid dictionary {}: {e}", path.display()),
+            Self::Solve(e) if f.alternate() => write!(f, "{e:#}"),
+            Self::Solve(e) => write!(f, "{e}"),
+            Self::Answers(path, e) => write!(f, "failed to access {}: {e}", path.display()),
+            Self::Scaffold(day, e) => write!(f, "failed to generate day {day:02}: {e}"),
+            Self::Extract(day, e) => write!(f, "failed to extract day {day:02} examples: {e}"),
+            Self::Fetch(day, e) => write!(f, "failed to fetch day {day:02} input: {e}"),
+            Self::Submit(day, part, e) => {
+                write!(f, "failed to submit day {day:02} part {part}: {e}")
+            }
+            Self::NotAccepted(day, part, verdict) => {
+                write!(f, "day {day:02} part {part} was not accepted: {verdict}")
+            }
+            Self::Check { drifted, failed } => {
+                write!(f, "{drifted} answers drifted and {failed} days failed")
+            }
+            Self::Validate { rejected } => {
+                write!(f, "{rejected} rejected in all, as reported above")
+            }
+        }
+    }
+}
+
+fn run(
+    day: u8,
+    parts: &[Part],
+    source: Source,
+    format: InputFormat,
+    policy: Policy,
+    backend: Backend,
+    day_one: &DayOne,
+) -> Result<(), RunError> {
+    let solver = find_day(day).ok_or(RunError::UnimplementedDay(day))?;
+    let input = source.read().map_err(|e| RunError::Input(source, e))?;
+    let parsed = match format {
+        InputFormat::Text => solver.parse_with(&input, policy, backend),
+        InputFormat::Json => solver.parse_json(&input),
+    }
+    .map_err(RunError::Solve)?;
+    if day_one.explain {
+        // https://no-color.org
+        let color = io::stdout().is_terminal()
+            && env::var_os("NO_COLOR").is_none_or(|no_color| no_color.is_empty());
+        let digits = day_one.digits.unwrap_or_default();
+        let explained = day01::explain(&input, policy, digits, color).map_err(RunError::Solve)?;
+        print!("{explained}");
+    }
+    let options = Options {
+        digits: day_one.digits.unwrap_or_default(),
+        no_digits: day_one.no_digits.unwrap_or_default(),
+    };
+    for &part in parts {
+        let calibration = match &day_one.dictionary {
+            Some(dictionary) if part == Part::Two => {
+                Some(dictionary.solve(&input, policy, options))
+            }
+            // also points lines without digits at the original input, which
+            // solving the parsed input can't
+            _ if day == 1 && format == InputFormat::Text => {
+                Some(day01::solve(&input, policy, part, options))
+            }
+            _ => None,
+        };
+        let answer = match calibration {
+            Some(calibration) => {
+                let calibration = calibration.map_err(RunError::Solve)?;
+                if calibration.affected > 0 {
+                    // only skipping or counting as 0 lets them through
+                    let what = match options.no_digits {
+                        NoDigits::Zero => "counted as 0",
+                        _ => "skipped",
+                    };
+                    let affected = calibration.affected;
+                    let lines = if affected == 1 { "line" } else { "lines" };
+                    eprintln!("day {day:02} part {part}: {affected} {lines} without digits {what}");
+                }
+                calibration.sum.to_string()
+            }
+            None => solver.solve(&parsed, part).map_err(RunError::Solve)?,
+        };
+        println!("day {day:02} part {part}: {answer}");
+    }
+    Ok(())
+}
+
+/// What `run` does differently for day 1.
+#[derive(Debug, Default)]
+struct DayOne {
+    /// Digit words for part 2, other than the English ones.
+    dictionary: Option<Dictionary>,
+    /// Explain every line before solving.
+    explain: bool,
+    /// What lines without digits do, if not the default.
+    no_digits: Option<NoDigits>,
+    /// Which characters are digits, if not the default.
+    digits: Option<Digits>,
+}
+
+impl DayOne {
+    /// Reads the dictionary, if there is one, from a TOML file.
+    fn new(
+        day: u8,
+        dictionary: Option<PathBuf>,
+        explain: bool,
+        no_digits: Option<NoDigits>,
+        digits: Option<Digits>,
+    ) -> Result<Self, RunError> {
+        let options = [
+            ("--dictionary", dictionary.is_some()),
+            ("--explain", explain),
+            ("--no-digits", no_digits.is_some()),
+            ("--digits", digits.is_some()),
+        ];
+        if let Some((option, _)) = options.iter().find(|(_, given)| *given && day != 1) {
+            return Err(RunError::DayOne(option, day));
+        }
+        let dictionary = match dictionary {
+            Some(path) => {
+                let source = Source::File(path.clone());
+                let toml = source.read().map_err(|e| RunError::Input(source, e))?;
+                Some(toml.parse().map_err(|e| RunError::Dictionary(path, e))?)
+            }
+            None => None,
+        };
+        Ok(DayOne {
+            dictionary,
+            explain,
+            no_digits,
+            digits,
+        })
+    }
+}
+
+fn stream(day: u8, parts: &[Part], source: Source, policy: Policy) -> Result<(), RunError> {
+    let solver = find_day(day).ok_or(RunError::UnimplementedDay(day))?;
+    if source == Source::Stdin && parts.len() > 1 {
+        return Err(RunError::StreamStdin);
+    }
+    for &part in parts {
+        let mut reader = source
+            .open()
+            .map_err(|e| RunError::Input(source.clone(), e))?;
+        let answer = solver
+            .stream(&mut reader, policy, part)
+            .map_err(|e| match e {
+                StreamError::Read(e) => RunError::Input(source.clone(), e),
+                StreamError::Solve(e) => RunError::Solve(e),
+            })?;
+        println!("day {day:02} part {part}: {answer}");
+    }
+    Ok(())
+}
+
+fn parse(
+    day: u8,
+    source: Source,
+    policy: Policy,
+    backend: Backend,
+    format: Output,
+) -> Result<(), RunError> {
+    let solver = find_day(day).ok_or(RunError::UnimplementedDay(day))?;
+    let input = source.read().map_err(|e| RunError::Input(source, e))?;
+    let parsed = solver
+        .parse_with(&input, policy, backend)
+        .map_err(RunError::Solve)?;
+    match format {
+        Output::Json => println!("{}", solver.to_json(&parsed)),
+    }
+    Ok(())
+}
+
+fn check(input_dir: &Path, policy: Policy, backend: Backend, record: bool) -> Result<(), RunError> {
+    let path = input_dir.join(answers::FILE_NAME);
+    let mut answers = Answers::load(&path).map_err(|e| RunError::Answers(path.clone(), e))?;
+    let mut recorded = 0;
+    let mut drifted = 0;
+    let mut failed = 0;
+    for solver in DAYS {
+        let day = solver.number();
+        let source = Source::day(input_dir, day);
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                println!("day {day:02}: no input");
+                continue;
+            }
+            Err(e) => {
+                eprintln!("error: {}", RunError::Input(source, e));
+                failed += 1;
+                continue;
+            }
+        };
+        let hash = InputHash::of(&input);
+        let parsed = match solver.parse_with(&input, policy, backend) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                eprintln!("error: {:#}", RunError::Solve(e));
+                failed += 1;
+                continue;
+            }
+        };
+        for part in Part::BOTH {
+            let answer = match solver.solve(&parsed, part) {
+                Ok(answer) => answer,
+                Err(Error::Unimplemented { .. }) => continue,
+                Err(e) => {
+                    eprintln!("error: {:#}", RunError::Solve(e));
+                    failed += 1;
+                    continue;
+                }
+            };
+            match answers.get(day, part, hash) {
+                Some(expected) if expected == answer => {
+                    println!("day {day:02} part {part}: {answer} ok");
+                }
+                Some(expected) => {
+                    println!("day {day:02} part {part}: {answer} DRIFT (expected {expected})");
+                    drifted += 1;
+                }
+                None if record => {
+                    println!("day {day:02} part {part}: {answer} recorded");
+                    answers.record(day, part, hash, answer);
+                    recorded += 1;
+                }
+                None => println!("day {day:02} part {part}: {answer} unknown"),
+            }
+        }
+    }
+    if recorded > 0 {
+        answers
+            .save(&path)
+            .map_err(|e| RunError::Answers(path.clone(), e))?;
+    }
+    if drifted > 0 || failed > 0 {
+        return Err(RunError::Check { drifted, failed });
+    }
+    Ok(())
+}
+
+fn bench(
+    days: &[u8],
+    input_dir: &Path,
+    input: Option<Source>,
+    policy: Policy,
+    iterations: usize,
+    format: Format,
+) -> Result<(), RunError> {
+    let mut measurements = Vec::new();
+    for &day in days {
+        let solver = find_day(day).ok_or(RunError::UnimplementedDay(day))?;
+        let source = input.clone().unwrap_or_else(|| Source::day(input_dir, day));
+        let input = match source.read() {
+            Ok(input) => input,
+            // Skip days without an input when benchmarking everything.
+            Err(e) if e.kind() == io::ErrorKind::NotFound && days.len() > 1 => {
+                eprintln!("day {day:02}: no input");
+                continue;
+            }
+            Err(e) => return Err(RunError::Input(source, e)),
+        };
+        let mut results =
+            bench::bench(solver, &input, policy, iterations).map_err(RunError::Solve)?;
+        measurements.append(&mut results);
+    }
+    print!("{}", bench::render(&measurements, format));
+    Ok(())
+}
+
+fn validate(
+    days: &[u8],
+    input_dir: &Path,
+    input: Option<Source>,
+    policy: Policy,
+) -> Result<(), RunError> {
+    let mut rejected = 0;
+    for &day in days {
+        let solver = find_day(day).ok_or(RunError::UnimplementedDay(day))?;
+        let source = input.clone().unwrap_or_else(|| Source::day(input_dir, day));
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(e) if e.kind() == io::ErrorKind::NotFound && days.len() > 1 => {
+                println!("day {day:02}: no input");
+                continue;
+            }
+            Err(e) => return Err(RunError::Input(source, e)),
+        };
+        let report = solver.validate(&input, policy);
+        for e in &report.errors {
+            eprintln!("error: {e:#}");
+        }
+        println!("day {day:02}: {report}");
+        rejected += report.rejected;
+    }
+    if rejected > 0 {
+        return Err(RunError::Validate { rejected });
+    }
+    Ok(())
+}
+
+fn new_day(day: u8, input_dir: &Path, pest: bool) -> Result<(), RunError> {
+    let written = scaffold::generate(Path::new(scaffold::CRATE_DIR), input_dir, day, pest)
+        .map_err(|e| RunError::Scaffold(day, e))?;
+    for path in written {
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn extract(day: u8, input_dir: &Path, page: Source) -> Result<(), RunError> {
+    let html = page.read().map_err(|e| RunError::Input(page, e))?;
+    let extracted = examples::extract(&html);
+    let written =
+        examples::write(input_dir, day, &extracted).map_err(|e| RunError::Extract(day, e))?;
+    for path in written {
+        println!("wrote {}", path.display());
+    }
+    for examples::Expected { file, part, answer } in &extracted.manifest.0 {
+        println!("part {part}: {file} should produce {answer}");
+    }
+    Ok(())
+}
+
+fn fetch(days: &[u8], input_dir: &Path, server: Server) -> Result<(), RunError> {
+    let client = server.client(input_dir);
+    for &day in days {
+        match client
+            .fetch(input_dir, day)
+            .map_err(|e| RunError::Fetch(day, e))?
+        {
+            Fetched::Cached(path) => println!("day {day:02}: cached at {}", path.display()),
+            Fetched::Downloaded(path) => println!("day {day:02}: saved to {}", path.display()),
+        }
+    }
+    Ok(())
+}
+
+fn submit(
+    day: u8,
+    part: Part,
+    answer: Option<String>,
+    input_dir: &Path,
+    policy: Policy,
+    backend: Backend,
+    server: Server,
+) -> Result<(), RunError> {
+    let source = Source::day(input_dir, day);
+    let input = source.read().map_err(|e| RunError::Input(source, e))?;
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let solver = find_day(day).ok_or(RunError::UnimplementedDay(day))?;
+            let parsed = solver
+                .parse_with(&input, policy, backend)
+                .map_err(RunError::Solve)?;
+            solver.solve(&parsed, part).map_err(RunError::Solve)?
+        }
+    };
+    println!("day {day:02} part {part}: submitting {answer}");
+    let client = server.client(input_dir);
+    let verdict = submit::submit(&client, input_dir, day, part, &answer)
+        .map_err(|e| RunError::Submit(day, part, e))?;
+    if verdict != Verdict::Correct {
+        return Err(RunError::NotAccepted(day, part, verdict));
+    }
+    println!("day {day:02} part {part}: correct");
+    // remember the accepted answer so `check` catches regressions
+    let path = input_dir.join(answers::FILE_NAME);
+    let mut answers = Answers::load(&path).map_err(|e| RunError::Answers(path.clone(), e))?;
+    answers.record(day, part, InputHash::of(&input), answer);
+    answers
+        .save(&path)
+        .map_err(|e| RunError::Answers(path.clone(), e))
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            input_format: _,
+            stream: true,
+            dictionary: _,
+            explain: _,
+            no_digits: _,
+            digits: _,
+        } => {
+            let source = input.unwrap_or_else(|| Source::day(&cli.input_dir, day));
+            match part {
+                Some(part) => self::stream(day, &[part], source, cli.policy),
+                None => self::stream(day, &Part::BOTH, source, cli.policy),
+            }
+        }
+        Command::Run {
+            day,
+            part,
+            input,
+            input_format,
+            stream: false,
+            dictionary,
+            explain,
+            no_digits,
+            digits,
+        } => {
+            let source = input.unwrap_or_else(|| Source::day(&cli.input_dir, day));
+            let parts = match &part {
+                Some(part) => std::slice::from_ref(part),
+                None => &Part::BOTH,
+            };
+            DayOne::new(day, dictionary, explain, no_digits, digits).and_then(|day_one| {
+                let (policy, backend) = (cli.policy, cli.backend);
+                run(day, parts, source, input_format, policy, backend, &day_one)
+            })
+        }
+        Command::Parse { day, input, format } => {
+            let source = input.unwrap_or_else(|| Source::day(&cli.input_dir, day));
+            parse(day, source, cli.policy, cli.backend, format)
+        }
+        Command::Check { record } => check(&cli.input_dir, cli.policy, cli.backend, record),
+        Command::Bench {
+            day,
+            iterations,
+            format,
+            input,
+        } => {
+            let days: Vec<u8> = match day {
+                Some(day) => vec![day],
+                None => DAYS.iter().map(|solver| solver.number()).collect(),
+            };
+            bench(&days, &cli.input_dir, input, cli.policy, iterations, format)
+        }
+        Command::Validate { day, input } => {
+            let days: Vec<u8> = match day {
+                Some(day) => vec![day],
+                None => DAYS.iter().map(|solver| solver.number()).collect(),
+            };
+            validate(&days, &cli.input_dir, input, cli.policy)
+        }
+        Command::New { day, pest } => new_day(day, &cli.input_dir, pest),
+        Command::Extract { day, page } => extract(day, &cli.input_dir, page),
+        Command::Fetch { mut days, server } => {
+            if days.is_empty() {
+                days = DAYS.iter().map(|solver| solver.number()).collect();
+            }
+            fetch(&days, &cli.input_dir, server)
+        }
+        Command::Submit {
+            day,
+            part,
+            answer,
+            server,
+        } => submit(
+            day,
+            part,
+            answer,
+            &cli.input_dir,
+            cli.policy,
+            cli.backend,
+            server,
+        ),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e:#}");
+            ExitCode::FAILURE
+        }
+    }
 }
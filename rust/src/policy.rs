@@ -0,0 +1,232 @@
+//! How forgiving parsing is about the layout of an input.
+//!
+//! The grammars only accept well-formed inputs. The strict policy also
+//! rejects CRLF line endings, stray blank lines, tabs, trailing whitespace
+//! and a missing final newline up front, while the lenient policy
+//! normalizes them before parsing.
+
+use std::borrow::Cow;
+
+use clap::ValueEnum;
+
+use crate::error::{Error, Span};
+
+/// How a day's input is split up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    /// One item per line, without blank lines.
+    Lines,
+    /// Sections separated by single blank lines.
+    Sections,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Policy {
+    /// Normalize line endings, tabs, trailing whitespace and blank lines.
+    #[default]
+    Lenient,
+    /// Reject anything that needs normalizing.
+    Strict,
+}
+
+/// An input ready for parsing, remembering where its lines came from.
+#[derive(Debug, PartialEq)]
+pub struct Prepared<'a> {
+    pub text: Cow<'a, str>,
+    /// The original (0-based) line of every line of `text`, when lines were
+    /// dropped.
+    origins: Option<Vec<usize>>,
+}
+
+impl Prepared<'_> {
+    /// Points an error in the prepared text at the original input.
+    pub fn locate(&self, error: Error) -> Error {
+        match &self.origins {
+            Some(_) => error.map_line(|line| self.origin(line - 1) + 1),
+            None => error,
+        }
+    }
+
+    /// The original (0-based) line of a line of `text`.
+    pub fn origin(&self, index: usize) -> usize {
+        let origin = self.origins.as_ref().and_then(|origins| origins.get(index));
+        origin.copied().unwrap_or(index)
+    }
+}
+
+impl Policy {
+    /// Everything about the layout of `input` that the policy rejects.
+    pub fn check(self, day: u8, layout: Layout, input: &str) -> Vec<Error> {
+        if self == Policy::Lenient {
+            return Vec::new();
+        }
+        let mut errors = Vec::new();
+        let lines: Vec<&str> = input.split_inclusive('\n').collect();
+        let mut offset = 0;
+        for (index, line) in lines.iter().enumerate() {
+            let content = line.trim_end_matches('\n');
+            let crlf = content.strip_suffix('\r');
+            let text = crlf.unwrap_or(content);
+            let separator = layout == Layout::Sections
+                && index > 0
+                && index + 1 < lines.len()
+                && !lines[index - 1].trim().is_empty();
+            // one error per line, so that reports count lines, at the
+            // column of the problem if it has one
+            // whitespace is pointed at itself, as it isn't a word
+            let whitespace = |start: usize, end: usize| Span {
+                line: index + 1,
+                column: text[..start].chars().count() + 1,
+                text: text[start..end].to_string(),
+                source: text.to_string(),
+            };
+            let problem = if line.trim().is_empty() {
+                (!separator).then(|| (Span::line(index, text), "blank line"))
+            } else if crlf.is_some() {
+                Some((Span::at(input, offset + text.len()), "CRLF line ending"))
+            } else if let Some(column) = text.find('\t') {
+                Some((whitespace(column, column + 1), "tab"))
+            } else if text.trim_end() != text {
+                let trimmed = text.trim_end().len();
+                Some((whitespace(trimmed, text.len()), "trailing whitespace"))
+            } else if !line.ends_with('\n') {
+                Some((
+                    Span::at(input, offset + text.len()),
+                    "missing final newline",
+                ))
+            } else {
+                None
+            };
+            if let Some((span, message)) = problem {
+                errors.push(Error::syntax(day, span, message));
+            }
+            offset += line.len();
+        }
+        errors
+    }
+
+    /// The input as the parser should see it. `tidy` is applied to every
+    /// line under the lenient policy, for day-specific clean-up.
+    pub fn apply<'a>(
+        self,
+        layout: Layout,
+        input: &'a str,
+        tidy: fn(&str) -> Cow<'_, str>,
+    ) -> Prepared<'a> {
+        if self == Policy::Strict {
+            return Prepared {
+                text: Cow::Borrowed(input),
+                origins: None,
+            };
+        }
+        let mut text = String::with_capacity(input.len());
+        let mut origins = Vec::new();
+        let mut blank = false;
+        for (index, line) in input.lines().enumerate() {
+            let line = line.replace('\t', " ");
+            let line = line.trim_end();
+            if line.is_empty() {
+                blank = true;
+                continue;
+            }
+            // collapse runs of blank lines into one separator, and drop them
+            // at the start
+            if blank && layout == Layout::Sections && !origins.is_empty() {
+                text.push('\n');
+                origins.push(index - 1);
+            }
+            blank = false;
+            text += &tidy(line);
+            text.push('\n');
+            origins.push(index);
+        }
+        Prepared {
+            text: Cow::Owned(text),
+            origins: Some(origins),
+        }
+    }
+
+    /// Rejects the input on its first layout error, or prepares it.
+    pub fn prepare<'a>(
+        self,
+        day: u8,
+        layout: Layout,
+        input: &'a str,
+        tidy: fn(&str) -> Cow<'_, str>,
+    ) -> Result<Prepared<'a>, Error> {
+        match self.check(day, layout, input).into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(self.apply(layout, input, tidy)),
+        }
+    }
+}
+
+#[test]
+fn strict() {
+    let lines = |errors: Vec<Error>| -> Vec<(usize, String)> {
+        errors
+            .iter()
+            .map(|e| match e {
+                Error::Syntax { span, message, .. } => (span.line, message.clone()),
+                _ => unreachable!(),
+            })
+            .collect()
+    };
+    let input = "a\r\nb\n\nc\n";
+    assert_eq!(
+        lines(Policy::Strict.check(1, Layout::Lines, input)),
+        vec![(1, "CRLF line ending".into()), (3, "blank line".into())]
+    );
+    assert_eq!(Policy::Strict.check(1, Layout::Sections, input).len(), 1);
+    assert_eq!(
+        lines(Policy::Strict.check(5, Layout::Sections, "\na\n\n\nb\n\n")),
+        vec![
+            (1, "blank line".into()),
+            (4, "blank line".into()),
+            (6, "blank line".into())
+        ]
+    );
+    assert!(Policy::Lenient.check(1, Layout::Lines, input).is_empty());
+}
+
+#[test]
+fn strict_normalizing() {
+    // everything the lenient policy normalizes, where it is
+    let errors = |input| -> Vec<String> {
+        let errors = Policy::Strict.check(1, Layout::Lines, input);
+        errors.iter().map(Error::to_string).collect()
+    };
+    assert_eq!(
+        errors("1\tabc2\n"),
+        ["day 01, line 1, column 2, at \"\\t\": tab"]
+    );
+    assert_eq!(
+        errors("1abc2 \n"),
+        ["day 01, line 1, column 6, at \" \": trailing whitespace"]
+    );
+    assert_eq!(
+        errors("1abc2\n3x4"),
+        ["day 01, line 2, column 4, at end of line: missing final newline"]
+    );
+    assert_eq!(
+        errors("a b\r\n")[0],
+        "day 01, line 1, column 4, at end of line: CRLF line ending"
+    );
+    assert!(errors("a b\n").is_empty());
+    assert!(errors("").is_empty());
+}
+
+#[test]
+fn lenient() {
+    let input = "\r\na\tb  \r\n\n\n c\nd";
+    let prepared = Policy::Lenient.apply(Layout::Lines, input, |line| Cow::Borrowed(line));
+    assert_eq!(prepared.text, "a b\n c\nd\n");
+    let prepared = Policy::Lenient.apply(Layout::Sections, input, |line| Cow::Borrowed(line));
+    assert_eq!(prepared.text, "a b\n\n c\nd\n");
+    // errors on the prepared text point at the original lines
+    let error = Error::syntax(1, Span::line(2, " c"), "oops");
+    assert_eq!(
+        prepared.locate(error).to_string(),
+        "day 01, line 5, column 1, at \" c\": oops"
+    );
+}
@@ -0,0 +1,282 @@
+//! Generating the files for a new day and registering it in `lib.rs` (and
+//! its fuzz target in `fuzz/Cargo.toml`).
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::input;
+
+/// The crate this binary was built from.
+pub const CRATE_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+/// Solver module for a day, with an example test to fill in.
+pub fn module_source(day: u8, pest: bool) -> String {
+    let parse = if pest {
+        r#"        let lines = InputParser::parse(Rule::input, input)
+            .map_err(|e| Error::pest(Self::DAY, &e, |_| None))?
+            .next()
+            .expect("a parsed input")
+            .into_inner()
+            .filter(|pair| pair.as_rule() == Rule::line)
+            .map(|line| line.as_str().to_string())
+            .collect();
+        Ok(lines)"#
+    } else {
+        "        Ok(input.lines().map(String::from).collect())"
+    };
+    let imports = if pest {
+        format!(
+            r#"use pest::Parser;
+use pest_derive::Parser;
+
+use crate::error::Error;
+use crate::solution::Solution;
+
+#[derive(Parser)]
+#[grammar = "day{day:02}.pest"]
+struct InputParser;
+"#
+        )
+    } else {
+        "use crate::error::Error;\nuse crate::solution::Solution;\n".to_string()
+    };
+    format!(
+        r#"{imports}
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    const DAY: u8 = {day};
+
+    type Input = Vec<String>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {{
+{parse}
+    }}
+}}
+
+#[test]
+#[ignore = "fill in the example answer"]
+fn example01() {{
+    let example = include_str!("../../input/day{day:02}/example01.txt");
+    let input = Day{day:02}::parse(example).unwrap();
+    assert_eq!(Day{day:02}::part1(&input), Ok(0));
+}}
+"#
+    )
+}
+
+/// Pest grammar stub, splitting the input into lines.
+pub fn grammar_source() -> String {
+    r#"// one line of the puzzle input
+line = { (!NEWLINE ~ ANY)* }
+
+input = { SOI ~ line ~ (NEWLINE ~ line)* ~ EOI }
+"#
+    .to_string()
+}
+
+/// Fuzz target running a day's parser and solver on arbitrary input.
+pub fn fuzz_target_source(day: u8) -> String {
+    format!(
+        r#"#![no_main]
+
+use advent_of_code_2023::day{day:02}::Day{day:02};
+use advent_of_code_2023_fuzz::exercise;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {{
+    exercise(&Day{day:02}, input);
+}});
+"#
+    )
+}
+
+/// Adds the `[[bin]]` entry of a day's fuzz target to the source of the
+/// fuzz crate's `Cargo.toml`.
+pub fn register_fuzz_target(manifest: &str, day: u8) -> Result<String, String> {
+    let name = format!("name = \"day{day:02}\"");
+    if manifest.lines().any(|line| line == name) {
+        return Err(format!("day {day:02} already has a fuzz target"));
+    }
+    let mut source = manifest.trim_end().to_string();
+    source += &format!(
+        "\n\n[[bin]]\n{name}\npath = \"fuzz_targets/day{day:02}.rs\"\ntest = false\ndoc = false\nbench = false\n"
+    );
+    Ok(source)
+}
+
+/// Adds `pub mod dayNN;` and its entry in `DAYS` to the source of `lib.rs`,
+/// keeping both in day order.
+pub fn register(lib: &str, day: u8) -> Result<String, String> {
+    let module = format!("pub mod day{day:02};");
+    let entry = format!("    &day{day:02}::Day{day:02},");
+    if lib.lines().any(|line| line == module) {
+        return Err(format!("day {day:02} is already registered"));
+    }
+    let lines: Vec<&str> = lib.lines().collect();
+    let insert_after = |prefix: &str, suffix: &str, new: &str| -> Result<usize, String> {
+        // the last existing line for an earlier day, or the first line if
+        // the new day comes before all of them
+        let days: Vec<(usize, u8)> = lines
+            .iter()
+            .enumerate()
+            .filter_map(|(index, line)| {
+                let number = line.strip_prefix(prefix)?.get(..2)?.parse::<u8>().ok()?;
+                line.ends_with(suffix).then_some((index, number))
+            })
+            .collect();
+        match days.iter().rev().find(|(_, number)| *number < day) {
+            Some((index, _)) => Ok(index + 1),
+            None => days
+                .first()
+                .map(|(index, _)| *index)
+                .ok_or(format!("nowhere to insert {new:?}")),
+        }
+    };
+    let module_at = insert_after("pub mod day", ";", &module)?;
+    let entry_at = insert_after("    &day", ",", &entry)?;
+    let mut source = String::new();
+    for (index, line) in lines.iter().enumerate() {
+        if index == module_at {
+            source += &module;
+            source.push('\n');
+        }
+        if index == entry_at {
+            source += &entry;
+            source.push('\n');
+        }
+        source += line;
+        source.push('\n');
+    }
+    Ok(source)
+}
+
+/// Creates the solver module, optional grammar, example input and fuzz
+/// target (when there is a fuzz crate) for a day, and registers it in
+/// `lib.rs`. Returns the files that were written.
+pub fn generate(
+    crate_dir: &Path,
+    input_dir: &Path,
+    day: u8,
+    pest: bool,
+) -> io::Result<Vec<PathBuf>> {
+    let src = crate_dir.join("src");
+    let module = src.join(format!("day{day:02}.rs"));
+    let grammar = src.join(format!("day{day:02}.pest"));
+    let examples = input::day_dir(input_dir, day);
+    let example = examples.join("example01.txt");
+    let lib = src.join("lib.rs");
+    let fuzz = crate_dir.join("fuzz");
+    let fuzz_manifest = fuzz.join("Cargo.toml");
+    let fuzz_target = fuzz.join(format!("fuzz_targets/day{day:02}.rs"));
+
+    for path in [&module, &grammar, &example, &fuzz_target] {
+        if path.exists() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} already exists", path.display()),
+            ));
+        }
+    }
+    let invalid = |e| io::Error::new(io::ErrorKind::InvalidData, e);
+    let registered = register(&fs::read_to_string(&lib)?, day).map_err(invalid)?;
+    let fuzzed = match fs::read_to_string(&fuzz_manifest) {
+        Ok(manifest) => Some(register_fuzz_target(&manifest, day).map_err(invalid)?),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+        Err(e) => return Err(e),
+    };
+
+    let mut written = vec![module.clone()];
+    fs::write(&module, module_source(day, pest))?;
+    if pest {
+        fs::write(&grammar, grammar_source())?;
+        written.push(grammar);
+    }
+    fs::create_dir_all(&examples)?;
+    fs::write(&example, "")?;
+    written.push(example);
+    fs::write(&lib, registered)?;
+    written.push(lib);
+    if let Some(fuzzed) = fuzzed {
+        fs::create_dir_all(fuzz.join("fuzz_targets"))?;
+        fs::write(&fuzz_target, fuzz_target_source(day))?;
+        written.push(fuzz_target);
+        fs::write(&fuzz_manifest, fuzzed)?;
+        written.push(fuzz_manifest);
+    }
+    Ok(written)
+}
+
+#[test]
+fn registration() {
+    let lib = "pub mod day01;\npub mod day03;\npub mod input;\n\npub static DAYS: &[&dyn Day] = &[\n    &day01::Day01,\n    &day03::Day03,\n];\n";
+    assert_eq!(
+        register(lib, 2).unwrap(),
+        "pub mod day01;\npub mod day02;\npub mod day03;\npub mod input;\n\npub static DAYS: &[&dyn Day] = &[\n    &day01::Day01,\n    &day02::Day02,\n    &day03::Day03,\n];\n"
+    );
+    assert_eq!(
+        register(lib, 6).unwrap(),
+        "pub mod day01;\npub mod day03;\npub mod day06;\npub mod input;\n\npub static DAYS: &[&dyn Day] = &[\n    &day01::Day01,\n    &day03::Day03,\n    &day06::Day06,\n];\n"
+    );
+    assert!(register(lib, 3).is_err());
+}
+
+#[test]
+fn registers_fuzz_target() {
+    let manifest = include_str!("../fuzz/Cargo.toml");
+    let Some(day) = (1..=25).find(|&day| crate::find_day(day).is_none()) else {
+        return;
+    };
+    let registered = register_fuzz_target(manifest, day).unwrap();
+    assert!(registered.starts_with(manifest));
+    assert!(register_fuzz_target(manifest, 1).is_err());
+}
+
+#[test]
+fn registers_current_lib() {
+    let Some(day) = (1..=25).find(|&day| crate::find_day(day).is_none()) else {
+        return;
+    };
+    let registered = register(include_str!("lib.rs"), day).unwrap();
+    assert!(registered.contains(&format!("pub mod day{day:02};\n")));
+    assert!(registered.contains(&format!("    &day{day:02}::Day{day:02},\n")));
+}
+
+#[test]
+fn generates_files() {
+    let dir = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+    let crate_dir = dir.join("rust");
+    let input_dir = dir.join("input");
+    fs::create_dir_all(crate_dir.join("src")).unwrap();
+    let lib = "pub mod day01;\n\npub static DAYS: &[&dyn Day] = &[\n    &day01::Day01,\n];\n";
+    fs::write(crate_dir.join("src/lib.rs"), lib).unwrap();
+
+    let written = generate(&crate_dir, &input_dir, 6, true).unwrap();
+    assert_eq!(written.len(), 4);
+    assert!(fs::read_to_string(crate_dir.join("src/day06.rs"))
+        .unwrap()
+        .contains("#[grammar = \"day06.pest\"]"));
+    assert!(fs::read_to_string(crate_dir.join("src/lib.rs"))
+        .unwrap()
+        .contains("    &day06::Day06,\n"));
+    assert!(input_dir.join("day06/example01.txt").exists());
+    assert!(generate(&crate_dir, &input_dir, 6, false).is_err());
+
+    // with a fuzz crate, the day also gets a fuzz target
+    fs::create_dir_all(crate_dir.join("fuzz")).unwrap();
+    fs::write(crate_dir.join("fuzz/Cargo.toml"), "[package]\n").unwrap();
+    let written = generate(&crate_dir, &input_dir, 7, false).unwrap();
+    assert_eq!(written.len(), 5);
+    assert!(
+        fs::read_to_string(crate_dir.join("fuzz/fuzz_targets/day07.rs"))
+            .unwrap()
+            .contains("exercise(&Day07, input);")
+    );
+    assert!(fs::read_to_string(crate_dir.join("fuzz/Cargo.toml"))
+        .unwrap()
+        .contains("name = \"day07\"\npath = \"fuzz_targets/day07.rs\""));
+
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,284 @@
+//! The interface shared by every day's solver.
+
+use std::any::Any;
+use std::borrow::Cow;
+use std::fmt;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use clap::ValueEnum;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::backend::Backend;
+use crate::error::Error;
+use crate::policy::{Layout, Policy};
+use crate::stream::StreamError;
+use crate::validate::Report;
+
+/// One half of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, ValueEnum)]
+pub enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part {s:?}")),
+        }
+    }
+}
+
+/// A day's puzzle, split into parsing and solving each part.
+pub trait Solution {
+    /// Day of the puzzle (1-25).
+    const DAY: u8;
+
+    /// The parsed puzzle input, shared by both parts. It can be exported
+    /// as JSON, and solved from JSON instead of puzzle text.
+    type Input: Serialize + DeserializeOwned;
+    type Answer: fmt::Display;
+
+    /// How the input is split up, for the parsing [`Policy`].
+    const LAYOUT: Layout = Layout::Lines;
+
+    /// Day-specific clean-up of a line under the lenient policy, after
+    /// line endings, tabs and trailing whitespace have been normalized.
+    fn tidy(line: &str) -> Cow<'_, str> {
+        Cow::Borrowed(line)
+    }
+
+    /// The parser backends of the day, starting with the one
+    /// [`Solution::parse`] uses. Empty for days parsed without a library.
+    const BACKENDS: &'static [Backend] = &[];
+
+    /// Parses a well-formed input; see [`Day::parse_input`] for inputs that
+    /// may need normalizing first.
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+
+    /// Parses with one of [`Solution::BACKENDS`]. Defaults to
+    /// [`Solution::parse`], for days with a single parser.
+    fn parse_with(_backend: Backend, input: &str) -> Result<Self::Input, Error> {
+        Self::parse(input)
+    }
+
+    /// Parses as much of the input as possible, reporting every error.
+    /// Defaults to parsing the input as a whole.
+    fn validate(input: &str) -> Report {
+        let mut report = Report::new("inputs");
+        report.add(Self::parse(input));
+        report
+    }
+
+    /// [`Error::Unimplemented`] until part 1 has been solved.
+    fn part1(_input: &Self::Input) -> Result<Self::Answer, Error> {
+        Err(Error::Unimplemented {
+            day: Self::DAY,
+            part: Part::One,
+        })
+    }
+
+    /// [`Error::Unimplemented`] until part 2 has been solved.
+    fn part2(_input: &Self::Input) -> Result<Self::Answer, Error> {
+        Err(Error::Unimplemented {
+            day: Self::DAY,
+            part: Part::Two,
+        })
+    }
+
+    /// Solves a part from a reader one line at a time (see
+    /// [`stream::lines`](crate::stream::lines)), in memory that doesn't grow
+    /// with the input. [`Error::Unimplemented`] for days that need the whole
+    /// input.
+    fn stream(
+        _reader: impl BufRead,
+        _policy: Policy,
+        part: Part,
+    ) -> Result<Self::Answer, StreamError> {
+        Err(Error::Unimplemented {
+            day: Self::DAY,
+            part,
+        }
+        .into())
+    }
+
+    /// Solves a part straight from the bytes of a well-formed input,
+    /// without allocating per line. [`Error::Unimplemented`] for days
+    /// without such a parser.
+    #[cfg(feature = "fast")]
+    fn solve_bytes(_input: &[u8], part: Part) -> Result<Self::Answer, Error> {
+        Err(Error::Unimplemented {
+            day: Self::DAY,
+            part,
+        })
+    }
+}
+
+/// A parsed input, only usable with the [`Day`] that parsed it.
+pub struct Parsed(Box<dyn Any>);
+
+impl Parsed {
+    fn get<S: Solution>(&self) -> &S::Input
+    where
+        S::Input: 'static,
+    {
+        self.0
+            .downcast_ref::<S::Input>()
+            .expect("input was parsed by another day")
+    }
+}
+
+/// A [`Solution`] with its input and answer types erased, so that every day
+/// can live in the same registry.
+pub trait Day: Sync {
+    fn number(&self) -> u8;
+
+    /// See [`Solution::BACKENDS`].
+    fn backends(&self) -> &'static [Backend];
+
+    /// Parses an input under a policy, with errors pointing at the
+    /// original input.
+    fn parse_input(&self, input: &str, policy: Policy) -> Result<Parsed, Error> {
+        self.parse_with(input, policy, Backend::default())
+    }
+
+    /// Like [`Day::parse_input`], with a parser backend for days that have
+    /// several.
+    fn parse_with(&self, input: &str, policy: Policy, backend: Backend) -> Result<Parsed, Error>;
+
+    /// Validates an input under a policy, also reporting every layout error.
+    fn validate(&self, input: &str, policy: Policy) -> Report;
+
+    /// The parsed input as pretty-printed JSON.
+    fn to_json(&self, input: &Parsed) -> String;
+
+    /// Reads a parsed input back from its JSON.
+    fn parse_json(&self, json: &str) -> Result<Parsed, Error>;
+
+    /// [`Error::Unimplemented`] if the part has not been solved yet.
+    fn solve(&self, input: &Parsed, part: Part) -> Result<String, Error>;
+
+    /// See [`Solution::stream`].
+    fn stream(
+        &self,
+        reader: &mut dyn BufRead,
+        policy: Policy,
+        part: Part,
+    ) -> Result<String, StreamError>;
+
+    /// Parses and solves a part of an input under a policy with
+    /// [`Solution::solve_bytes`].
+    #[cfg(feature = "fast")]
+    fn solve_bytes(&self, input: &str, policy: Policy, part: Part) -> Result<String, Error>;
+}
+
+impl<S> Day for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn number(&self) -> u8 {
+        S::DAY
+    }
+
+    fn backends(&self) -> &'static [Backend] {
+        S::BACKENDS
+    }
+
+    fn parse_with(&self, input: &str, policy: Policy, backend: Backend) -> Result<Parsed, Error> {
+        let prepared = policy.prepare(S::DAY, S::LAYOUT, input, S::tidy)?;
+        let parsed = S::parse_with(backend, &prepared.text).map_err(|e| prepared.locate(e))?;
+        Ok(Parsed(Box::new(parsed)))
+    }
+
+    fn validate(&self, input: &str, policy: Policy) -> Report {
+        let prepared = policy.apply(S::LAYOUT, input, S::tidy);
+        let mut report = S::validate(&prepared.text);
+        report.errors = report
+            .errors
+            .into_iter()
+            .map(|e| prepared.locate(e))
+            .collect();
+        report.reject_layout(policy.check(S::DAY, S::LAYOUT, input), S::LAYOUT, input);
+        report
+    }
+
+    fn to_json(&self, input: &Parsed) -> String {
+        serde_json::to_string_pretty(input.get::<S>()).expect("a parsed input is valid JSON")
+    }
+
+    fn parse_json(&self, json: &str) -> Result<Parsed, Error> {
+        let parsed: S::Input =
+            serde_json::from_str(json).map_err(|e| Error::json(S::DAY, json, &e))?;
+        Ok(Parsed(Box::new(parsed)))
+    }
+
+    fn solve(&self, input: &Parsed, part: Part) -> Result<String, Error> {
+        let input = input.get::<S>();
+        let answer = match part {
+            Part::One => S::part1(input),
+            Part::Two => S::part2(input),
+        };
+        answer.map(|answer| answer.to_string())
+    }
+
+    fn stream(
+        &self,
+        reader: &mut dyn BufRead,
+        policy: Policy,
+        part: Part,
+    ) -> Result<String, StreamError> {
+        S::stream(reader, policy, part).map(|answer| answer.to_string())
+    }
+
+    #[cfg(feature = "fast")]
+    fn solve_bytes(&self, input: &str, policy: Policy, part: Part) -> Result<String, Error> {
+        let prepared = policy.prepare(S::DAY, S::LAYOUT, input, S::tidy)?;
+        S::solve_bytes(prepared.text.as_bytes(), part)
+            .map(|answer| answer.to_string())
+            .map_err(|e| prepared.locate(e))
+    }
+}
+
+#[test]
+fn json_round_trip() {
+    use crate::input::{day_dir, DEFAULT_DIR};
+
+    for solver in crate::DAYS {
+        let day = solver.number();
+        let path = day_dir(DEFAULT_DIR.as_ref(), day).join("example01.txt");
+        let example = std::fs::read_to_string(path).unwrap();
+        let parsed = solver.parse_input(&example, Policy::Strict).unwrap();
+        let json = solver.to_json(&parsed);
+        let reparsed = solver.parse_json(&json).unwrap();
+        assert_eq!(solver.to_json(&reparsed), json, "day {day:02}");
+        for part in Part::BOTH {
+            assert_eq!(
+                solver.solve(&reparsed, part),
+                solver.solve(&parsed, part),
+                "day {day:02} part {part}"
+            );
+        }
+    }
+}
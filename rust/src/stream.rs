@@ -0,0 +1,102 @@
+//! Solving days whose input is independent lines from a reader, one line at
+//! a time, for inputs too large to read whole.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::io::{self, BufRead};
+
+use crate::error::Error;
+use crate::policy::{Layout, Policy};
+
+/// A streamed input that couldn't be read, or solved.
+#[derive(Debug)]
+pub enum StreamError {
+    Read(io::Error),
+    Solve(Error),
+}
+
+impl From<io::Error> for StreamError {
+    fn from(error: io::Error) -> Self {
+        StreamError::Read(error)
+    }
+}
+
+impl From<Error> for StreamError {
+    fn from(error: Error) -> Self {
+        StreamError::Solve(error)
+    }
+}
+
+/// The alternate form (`{:#}`) is that of [`Error`].
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StreamError::Read(e) => write!(f, "failed to read the input: {e}"),
+            StreamError::Solve(e) => fmt::Display::fmt(e, f),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+/// Calls `f` with every line of `reader` and its (0-based) index, as the
+/// policy prepares a [`Layout::Lines`] input: lenient streams skip blank
+/// lines and tidy the others, strict streams stop at the first line the
+/// policy rejects. Only one line is held at a time, and `f`'s errors stop
+/// the stream, so a line that doesn't parse is reported before the layout
+/// errors of the lines after it.
+pub fn lines(
+    mut reader: impl BufRead,
+    day: u8,
+    policy: Policy,
+    tidy: fn(&str) -> Cow<'_, str>,
+    mut f: impl FnMut(usize, &str) -> Result<(), Error>,
+) -> Result<(), StreamError> {
+    let mut buffer = String::new();
+    let mut index = 0;
+    while reader.read_line(&mut buffer)? > 0 {
+        if let Some(error) = policy.check(day, Layout::Lines, &buffer).into_iter().next() {
+            return Err(error.on_line(index).into());
+        }
+        let prepared = policy.apply(Layout::Lines, &buffer, tidy);
+        if let Some(line) = prepared.text.lines().next() {
+            f(index, line)?;
+        }
+        buffer.clear();
+        index += 1;
+    }
+    Ok(())
+}
+
+#[test]
+fn policies() {
+    fn tidy(line: &str) -> Cow<'_, str> {
+        Cow::Borrowed(line)
+    }
+    let read = |input: &str, policy| {
+        let mut lines = Vec::new();
+        let result = self::lines(input.as_bytes(), 1, policy, tidy, |index, line| {
+            lines.push((index, line.to_string()));
+            Ok(())
+        });
+        (lines, result.map_err(|e| e.to_string()))
+    };
+    assert_eq!(
+        read("a\t \n\nb\r\n", Policy::Lenient),
+        (vec![(0, "a".into()), (2, "b".into())], Ok(()))
+    );
+    assert_eq!(
+        read("a\t \n\nb\r\n", Policy::Strict),
+        (
+            vec![],
+            Err("day 01, line 1, column 2, at \"\\t\": tab".into())
+        )
+    );
+    assert_eq!(
+        read("a\n\nb\n", Policy::Strict),
+        (
+            vec![(0, "a".into())],
+            Err("day 01, line 2, column 1, at end of line: blank line".into())
+        )
+    );
+}
@@ -0,0 +1,353 @@
+//! Submitting answers and remembering every attempt, so that an answer the
+//! server already rejected is never sent again.
+//!
+//! Attempts are recorded in `input/dayNN/submissions.txt` as
+//! `unix-time part answer verdict`:
+//!
+//! ```text
+//! # time part answer verdict
+//! 1701408000 1 54917 too-high
+//! 1701408065 1 54916 correct
+//! ```
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use regex::Regex;
+
+use crate::download::{Client, FetchError};
+use crate::examples;
+use crate::input;
+use crate::solution::Part;
+
+/// Name of the history file inside a day's input directory.
+pub const HISTORY: &str = "submissions.txt";
+
+/// What the server said about a submitted answer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    /// The answer was not checked; try again after the wait.
+    TooSoon(Option<Duration>),
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+    /// A response we don't recognize, as text.
+    Unknown(String),
+}
+
+impl Verdict {
+    /// Whether the server actually checked the answer.
+    pub fn checked(&self) -> bool {
+        matches!(
+            self,
+            Verdict::Correct | Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect
+        )
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too-high"),
+            Verdict::TooLow => write!(f, "too-low"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+            Verdict::TooSoon(Some(wait)) => write!(f, "too soon, wait {}s", wait.as_secs()),
+            Verdict::TooSoon(None) => write!(f, "too soon"),
+            Verdict::WrongLevel => write!(f, "wrong level (already solved?)"),
+            Verdict::Unknown(message) => write!(f, "unrecognized response: {message}"),
+        }
+    }
+}
+
+/// Reads the verdict out of the page the server responds with.
+pub fn parse_response(html: &str) -> Verdict {
+    let article = Regex::new(r"(?s)<article>(.*?)</article>").unwrap();
+    let message = article
+        .captures(html)
+        .map_or(html.to_string(), |article| examples::unescape(&article[1]));
+    if message.contains("That's the right answer") {
+        Verdict::Correct
+    } else if message.contains("That's not the right answer") {
+        if message.contains("too high") {
+            Verdict::TooHigh
+        } else if message.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Incorrect
+        }
+    } else if message.contains("You gave an answer too recently") {
+        let wait = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+        Verdict::TooSoon(wait.captures(&message).map(|wait| {
+            let minutes: u64 = wait.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+            let seconds: u64 = wait[2].parse().unwrap();
+            Duration::from_secs(minutes * 60 + seconds)
+        }))
+    } else if message.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        Verdict::Unknown(message.trim().to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attempt {
+    pub time: u64,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every checked answer submitted for a day.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct History(pub Vec<Attempt>);
+
+impl History {
+    /// The attempts in a day's history (see [`input::load`]).
+    pub fn load(day_dir: &Path) -> io::Result<Self> {
+        input::load(&day_dir.join(HISTORY))
+    }
+
+    pub fn save(&self, day_dir: &Path) -> io::Result<()> {
+        fs::create_dir_all(day_dir)?;
+        fs::write(day_dir.join(HISTORY), self.to_string())
+    }
+
+    /// Why submitting `answer` is pointless, if it is.
+    pub fn rejects(&self, part: Part, answer: &str) -> Option<String> {
+        let number = answer.parse::<i128>().ok();
+        for attempt in self.0.iter().filter(|attempt| attempt.part == part) {
+            let bound = attempt.answer.parse::<i128>().ok();
+            let reason = match (&attempt.verdict, number, bound) {
+                (Verdict::Correct, _, _) => Some("part is already solved".to_string()),
+                _ if attempt.answer == answer => {
+                    Some(format!("already submitted ({})", attempt.verdict))
+                }
+                (Verdict::TooHigh, Some(number), Some(bound)) if number >= bound => {
+                    Some(format!("{bound} was already too high"))
+                }
+                (Verdict::TooLow, Some(number), Some(bound)) if number <= bound => {
+                    Some(format!("{bound} was already too low"))
+                }
+                _ => None,
+            };
+            if reason.is_some() {
+                return reason;
+            }
+        }
+        None
+    }
+}
+
+impl FromStr for History {
+    type Err = String;
+
+    fn from_str(contents: &str) -> Result<Self, Self::Err> {
+        let mut attempts = Vec::new();
+        for (index, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let &[time, part, answer, verdict] = fields.as_slice() else {
+                return Err(format!("line {}: expected 4 fields", index + 1));
+            };
+            let time = time
+                .parse::<u64>()
+                .map_err(|e| format!("line {}: invalid time: {e}", index + 1))?;
+            let part = part
+                .parse::<Part>()
+                .map_err(|e| format!("line {}: {e}", index + 1))?;
+            let verdict = match verdict {
+                "correct" => Verdict::Correct,
+                "too-high" => Verdict::TooHigh,
+                "too-low" => Verdict::TooLow,
+                "incorrect" => Verdict::Incorrect,
+                _ => return Err(format!("line {}: invalid verdict {verdict:?}", index + 1)),
+            };
+            attempts.push(Attempt {
+                time,
+                part,
+                answer: answer.into(),
+                verdict,
+            });
+        }
+        Ok(History(attempts))
+    }
+}
+
+impl fmt::Display for History {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "# time part answer verdict")?;
+        for Attempt {
+            time,
+            part,
+            answer,
+            verdict,
+        } in &self.0
+        {
+            writeln!(f, "{time} {part} {answer} {verdict}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Rejected(String),
+    Fetch(FetchError),
+    History(io::Error),
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Rejected(reason) => write!(f, "not submitting: {reason}"),
+            Self::Fetch(e) => write!(f, "{e}"),
+            Self::History(e) => write!(f, "failed to update history: {e}"),
+        }
+    }
+}
+
+/// Posts an answer unless the history shows it can't be right, and records
+/// the attempt if the server checked it.
+pub fn submit(
+    client: &Client,
+    input_dir: &Path,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<Verdict, SubmitError> {
+    let dir = input::day_dir(input_dir, day);
+    let mut history = History::load(&dir).map_err(SubmitError::History)?;
+    if let Some(reason) = history.rejects(part, answer) {
+        return Err(SubmitError::Rejected(reason));
+    }
+    let level = part.to_string();
+    let response = client
+        .post(
+            &format!("{day}/answer"),
+            &[("level", &level), ("answer", answer)],
+        )
+        .map_err(SubmitError::Fetch)?;
+    let verdict = parse_response(&response);
+    if verdict.checked() {
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| time.as_secs());
+        history.0.push(Attempt {
+            time,
+            part,
+            answer: answer.into(),
+            verdict: verdict.clone(),
+        });
+        history.save(&dir).map_err(SubmitError::History)?;
+    }
+    Ok(verdict)
+}
+
+#[test]
+fn responses() {
+    let page = |message: &str| format!("<main>\n<article><p>{message}</p></article>\n</main>");
+    assert_eq!(
+        parse_response(&page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.")),
+        Verdict::Correct
+    );
+    assert_eq!(
+        parse_response(&page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; please wait one minute before trying again. <a href=\"/2023/day/1\">[Return to Day 1]</a>")),
+        Verdict::TooHigh
+    );
+    assert_eq!(
+        parse_response(&page(
+            "That's not the right answer; your answer is too low."
+        )),
+        Verdict::TooLow
+    );
+    assert_eq!(
+        parse_response(&page("That's not the right answer.  If you're stuck, make sure you're using the full input data.")),
+        Verdict::Incorrect
+    );
+    assert_eq!(
+        parse_response(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 39s left to wait.")),
+        Verdict::TooSoon(Some(Duration::from_secs(279)))
+    );
+    assert_eq!(
+        parse_response(&page(
+            "You gave an answer too recently.  You have 32s left to wait."
+        )),
+        Verdict::TooSoon(Some(Duration::from_secs(32)))
+    );
+    assert_eq!(
+        parse_response(&page(
+            "You don't seem to be solving the right level.  Did you already complete it?"
+        )),
+        Verdict::WrongLevel
+    );
+    assert!(matches!(
+        parse_response("<html></html>"),
+        Verdict::Unknown(_)
+    ));
+}
+
+#[test]
+fn history_rejects() {
+    let history: History = "1 1 500 too-high\n2 1 100 too-low\n3 1 250 incorrect\n4 2 7 correct\n"
+        .parse()
+        .unwrap();
+    assert_eq!(history.to_string().parse(), Ok(history.clone()));
+    assert!(history.rejects(Part::One, "500").is_some());
+    assert!(history.rejects(Part::One, "600").is_some());
+    assert!(history.rejects(Part::One, "100").is_some());
+    assert!(history.rejects(Part::One, "50").is_some());
+    assert!(history.rejects(Part::One, "250").is_some());
+    assert_eq!(history.rejects(Part::One, "300"), None);
+    assert!(history.rejects(Part::Two, "8").is_some());
+}
+
+#[test]
+fn submits_to_stub() {
+    use crate::download::{stub, RateLimiter, STAMP_FILE};
+
+    let dir = std::env::temp_dir().join(format!("aoc-submit-{}", std::process::id()));
+    let (url, requests) = stub::serve(vec![
+        (
+            200,
+            "<article><p>That's not the right answer; your answer is too low.</p></article>".into(),
+        ),
+        (
+            200,
+            "<article><p>That's the right answer!</p></article>".into(),
+        ),
+    ]);
+    let limiter = RateLimiter::new(Duration::ZERO, dir.join(STAMP_FILE));
+    let client = Client::new(&url, Some("secret".into()), limiter);
+
+    assert_eq!(
+        submit(&client, &dir, 1, Part::One, "40").unwrap(),
+        Verdict::TooLow
+    );
+    let request = requests.recv().unwrap();
+    assert_eq!(request.line, "POST /2023/day/1/answer HTTP/1.1");
+    assert_eq!(request.body, "level=1&answer=40");
+    assert!(matches!(
+        submit(&client, &dir, 1, Part::One, "39"),
+        Err(SubmitError::Rejected(_))
+    ));
+    assert_eq!(
+        submit(&client, &dir, 1, Part::One, "42").unwrap(),
+        Verdict::Correct
+    );
+    let history = History::load(&input::day_dir(&dir, 1)).unwrap();
+    let verdicts: Vec<&Verdict> = history.0.iter().map(|attempt| &attempt.verdict).collect();
+    assert_eq!(verdicts, vec![&Verdict::TooLow, &Verdict::Correct]);
+
+    fs::remove_dir_all(dir).unwrap();
+}
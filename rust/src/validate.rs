@@ -0,0 +1,130 @@
+//! Checking every line or section of an input, instead of stopping at the
+//! first one that doesn't parse.
+
+use std::fmt;
+
+use crate::error::Error;
+use crate::policy::Layout;
+
+/// What was accepted and rejected when validating an input.
+#[derive(Debug, PartialEq)]
+pub struct Report {
+    /// What the input is made of, e.g. "games".
+    pub unit: &'static str,
+    pub accepted: usize,
+    pub rejected: usize,
+    /// Why lines or sections were rejected, in input order. A section may
+    /// have several layout errors.
+    pub errors: Vec<Error>,
+}
+
+impl Report {
+    /// Validates an input with one item per line, parsed on its own.
+    pub fn lines<T>(
+        unit: &'static str,
+        input: &str,
+        parse: impl Fn(&str) -> Result<T, Error>,
+    ) -> Self {
+        let mut report = Report::new(unit);
+        for (index, line) in input.lines().enumerate() {
+            report.add(parse(line).map_err(|e| e.on_line(index)));
+        }
+        report
+    }
+
+    pub fn new(unit: &'static str) -> Self {
+        Report {
+            unit,
+            accepted: 0,
+            rejected: 0,
+            errors: Vec::new(),
+        }
+    }
+
+    pub fn add<T>(&mut self, result: Result<T, Error>) {
+        match result {
+            Ok(_) => self.accepted += 1,
+            Err(e) => {
+                self.rejected += 1;
+                self.errors.push(e);
+            }
+        }
+    }
+
+    /// Adds the layout errors of the parsing policy in `input`, replacing
+    /// the errors of the lines or sections they fall in. A line or section
+    /// that parsed but has layout errors is rejected instead.
+    pub fn reject_layout(&mut self, errors: Vec<Error>, layout: Layout, input: &str) {
+        let item = |line: Option<usize>| match layout {
+            Layout::Lines => line,
+            Layout::Sections => line.and_then(|line| section(input, line)),
+        };
+        let mut items: Vec<Option<usize>> = errors.iter().map(|e| item(e.line())).collect();
+        items.dedup();
+        for rejected in items {
+            let parsed = !self.errors.iter().any(|e| item(e.line()) == rejected);
+            if parsed {
+                self.accepted = self.accepted.saturating_sub(1);
+                self.rejected += 1;
+            }
+            self.errors.retain(|e| item(e.line()) != rejected);
+        }
+        self.errors.extend(errors);
+        self.errors.sort_by_key(Error::line);
+    }
+}
+
+/// The first (1-based) line of the section a line falls in. Blank lines
+/// belong to the section before them, or the first one at the start.
+fn section(input: &str, line: usize) -> Option<usize> {
+    let mut first = None;
+    let mut blank = true;
+    for (index, text) in input.lines().enumerate() {
+        if blank && !text.trim().is_empty() {
+            let start = index + 1;
+            if start <= line || first.is_none() {
+                first = Some(start);
+            }
+            if start >= line {
+                break;
+            }
+        }
+        blank = text.trim().is_empty();
+    }
+    first
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Report {
+            unit,
+            accepted,
+            rejected,
+            ..
+        } = self;
+        write!(
+            f,
+            "{accepted} of {} {unit} accepted, {rejected} rejected",
+            accepted + rejected
+        )
+    }
+}
+
+#[test]
+fn sections() {
+    let input = "\na\nb\n\n\nc\n\n";
+    let sections: Vec<Option<usize>> = (1..=7).map(|line| section(input, line)).collect();
+    assert_eq!(
+        sections,
+        [
+            Some(2),
+            Some(2),
+            Some(2),
+            Some(2),
+            Some(2),
+            Some(6),
+            Some(6)
+        ]
+    );
+    assert_eq!(section("\n\n", 1), None);
+}